@@ -1,10 +1,14 @@
-use crate::build::{BuildHandle, BuildMode};
+use crate::build::{BuildEvent, BuildHandle};
 use crate::config;
 use crate::engine;
+use crate::launch::LaunchSession;
 use crate::types::*;
 use std::path::PathBuf;
 use tokio::sync::mpsc;
 
+/// Folders with their size in bytes.
+type FolderSizes = Vec<(PathBuf, u64)>;
+
 /// Top-level application state.
 pub struct App {
     pub config: Config,
@@ -19,11 +23,55 @@ pub struct App {
     pub should_quit: bool,
     pub tick: u64,
     pub build_handle: Option<BuildHandle>,
-    pub log_rx: Option<mpsc::UnboundedReceiver<String>>,
+    pub log_rx: Option<mpsc::UnboundedReceiver<BuildEvent>>,
     pub auto_scroll_logs: bool,
     /// Brief status message shown in footer (e.g. "Copied!"), auto-clears.
     pub flash_message: Option<String>,
     pub flash_until: u64,
+    /// Configuration and platform of the current/last build (e.g. "DebugGame Linux").
+    pub build_label: Option<String>,
+    /// Jobs enqueued this session, in run order (finished jobs stay until cleared).
+    pub queue: Vec<QueuedJob>,
+    /// Set when the user cancels, so the queue does not start the next pending job.
+    pub queue_paused: bool,
+    /// Server and client processes started from a launch profile.
+    pub launch_session: Option<LaunchSession>,
+    /// Log tab shown in the log panel: 0 is the build log, then one per session process.
+    pub log_tab: usize,
+    /// Recorded builds, oldest first (persisted next to `config.json`).
+    pub history: Vec<BuildRecord>,
+    /// When the running job started, for its history record.
+    build_started: Option<chrono::DateTime<chrono::Local>>,
+    /// Index of the running job's first line in `logs`.
+    build_log_start: usize,
+    /// Lines of the running job trimmed from `logs`, so missing from its saved log.
+    build_log_dropped: usize,
+    /// Latest UBT action counter of the running build as `(done, total)`.
+    pub build_progress: Option<(usize, usize)>,
+    /// Set once the running build was asked to cancel, until its processes have stopped.
+    pub build_cancelling: bool,
+    /// Phase the running build is in.
+    pub build_phase: Option<BuildPhase>,
+    /// First phase of the running job that failed, named in its completion message.
+    failed_phase: Option<BuildPhase>,
+    /// Errors and warnings parsed from the build log, in log order.
+    pub diagnostics: Vec<Diagnostic>,
+    /// Selected row of the problems panel (index into `problem_order()`), if any.
+    pub problem_selected: Option<usize>,
+    /// Terminal editor the main loop should run with the TUI suspended.
+    pub pending_terminal_command: Option<crate::editor::EditorCommand>,
+    /// Actions of the running build that reported their duration, in seconds.
+    action_times: Vec<(String, f64)>,
+    /// Summary of the last finished build, shown in the summary dialog.
+    pub last_summary: Option<BuildSummary>,
+    /// Folders listed in the clean dialog, with their sizes once measured.
+    pub clean_candidates: Vec<CleanCandidate>,
+    /// Sizes measured in the background: a candidate's index and each folder it stands for.
+    clean_size_rx: Option<mpsc::UnboundedReceiver<(usize, FolderSizes)>>,
+    /// Result of a clean backup restore running in the background.
+    restore_rx: Option<mpsc::UnboundedReceiver<Result<Vec<String>, String>>>,
+    /// Measured size of every concrete folder behind the clean candidates.
+    clean_path_sizes: std::collections::HashMap<PathBuf, u64>,
 }
 
 impl App {
@@ -34,7 +82,7 @@ impl App {
             .selected_project_path
             .as_ref()
             .and_then(|path| cfg.projects.iter().position(|p| p.path == *path))
-            .or_else(|| {
+            .or({
                 if cfg.projects.is_empty() {
                     None
                 } else {
@@ -66,6 +114,28 @@ impl App {
             auto_scroll_logs: true,
             flash_message: None,
             flash_until: 0,
+            build_label: None,
+            queue: Vec::new(),
+            queue_paused: false,
+            launch_session: None,
+            log_tab: 0,
+            history: crate::history::load_history(),
+            build_started: None,
+            build_log_start: 0,
+            build_log_dropped: 0,
+            build_progress: None,
+            build_cancelling: false,
+            build_phase: None,
+            failed_phase: None,
+            diagnostics: Vec::new(),
+            problem_selected: None,
+            pending_terminal_command: None,
+            action_times: Vec::new(),
+            last_summary: None,
+            clean_candidates: Vec::new(),
+            clean_size_rx: None,
+            restore_rx: None,
+            clean_path_sizes: std::collections::HashMap::new(),
         }
     }
 
@@ -88,6 +158,11 @@ impl App {
             items.push(FocusItem::AddProject);
         }
 
+        // Problems
+        if !self.diagnostics.is_empty() {
+            items.push(FocusItem::Problems);
+        }
+
         // Engine
         items.push(FocusItem::Engine);
 
@@ -128,7 +203,10 @@ impl App {
 
     pub fn focus_next_panel(&mut self) {
         match self.focused_panel() {
-            FocusPanel::Projects => self.focus = FocusItem::Engine,
+            FocusPanel::Projects if !self.diagnostics.is_empty() => {
+                self.focus = FocusItem::Problems
+            }
+            FocusPanel::Projects | FocusPanel::Problems => self.focus = FocusItem::Engine,
             FocusPanel::Engine => self.focus = FocusItem::BuildButton(0),
             FocusPanel::Build => {
                 self.focus = FocusItem::Logs;
@@ -141,7 +219,8 @@ impl App {
     pub fn focus_prev_panel(&mut self) {
         match self.focused_panel() {
             FocusPanel::Projects => self.focus = FocusItem::Logs,
-            FocusPanel::Engine => self.focus = self.projects_anchor_item(),
+            FocusPanel::Engine if !self.diagnostics.is_empty() => self.focus = FocusItem::Problems,
+            FocusPanel::Engine | FocusPanel::Problems => self.focus = self.projects_anchor_item(),
             FocusPanel::Build => self.focus = FocusItem::Engine,
             FocusPanel::Logs => self.focus = FocusItem::BuildButton(0),
         }
@@ -153,7 +232,36 @@ impl App {
 
     pub fn follow_latest_logs(&mut self) {
         self.auto_scroll_logs = true;
-        self.log_scroll = self.logs.len().saturating_sub(1);
+        self.log_scroll = self.visible_logs().len().saturating_sub(1);
+    }
+
+    /// Lines of the log tab currently shown (the build log or a launched process).
+    pub fn visible_logs(&self) -> &[LogLine] {
+        self.log_tab
+            .checked_sub(1)
+            .and_then(|i| self.launch_session.as_ref()?.processes.get(i))
+            .map(|p| p.logs.as_slice())
+            .unwrap_or(&self.logs)
+    }
+
+    /// Labels of the log tabs: "Build", then one per launched process.
+    pub fn log_tabs(&self) -> Vec<String> {
+        let mut tabs = vec!["Build".to_string()];
+        if let Some(session) = &self.launch_session {
+            tabs.extend(session.processes.iter().map(|p| p.label.clone()));
+        }
+        tabs
+    }
+
+    /// Switch to the next (or previous) log tab and follow its latest lines.
+    pub fn cycle_log_tab(&mut self, forward: bool) {
+        let count = self.log_tabs().len();
+        self.log_tab = if forward {
+            (self.log_tab + 1) % count
+        } else {
+            (self.log_tab + count - 1) % count
+        };
+        self.follow_latest_logs();
     }
 
     fn projects_anchor_item(&self) -> FocusItem {
@@ -204,6 +312,21 @@ impl App {
             name,
             path: path.clone(),
             editor_target: None,
+            target: None,
+            platform: None,
+            configuration: None,
+            package: PackageSettings::default(),
+            extra_args: Vec::new(),
+            env: Default::default(),
+            pre_build_hooks: Vec::new(),
+            post_build_hooks: Vec::new(),
+            clean_dirs: None,
+            project_file_format: None,
+            clean_backup: false,
+            auto_launch: None,
+            launch_profiles: Vec::new(),
+            presets: Vec::new(),
+            active_preset: None,
         });
         // Focus the newly added project
         let idx = self.config.projects.len() - 1;
@@ -260,6 +383,500 @@ impl App {
         }
     }
 
+    /// Advance the selected project's target platform to the next one and persist it.
+    pub fn cycle_platform(&mut self) {
+        let Some(project) = self
+            .selected_project_index()
+            .and_then(|i| self.config.projects.get_mut(i))
+        else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        let platform = match project.active_preset_mut() {
+            Some(preset) => {
+                preset.platform = preset.platform.next();
+                preset.platform
+            }
+            None => {
+                let platform = project.platform().next();
+                project.platform = Some(platform);
+                platform
+            }
+        };
+        self.save_config();
+        self.flash_message = Some(format!("Platform set to {}", platform));
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Advance the selected project's build configuration to the next one and persist it.
+    pub fn cycle_configuration(&mut self) {
+        let Some(project) = self
+            .selected_project_index()
+            .and_then(|i| self.config.projects.get_mut(i))
+        else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        let configuration = match project.active_preset_mut() {
+            Some(preset) => {
+                preset.configuration = preset.configuration.next();
+                preset.configuration
+            }
+            None => {
+                let configuration = project.configuration().next();
+                project.configuration = Some(configuration);
+                configuration
+            }
+        };
+        self.save_config();
+        self.flash_message = Some(format!("Configuration set to {}", configuration));
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Switch the selected project to its next preset (after the last one, back to none).
+    pub fn cycle_preset(&mut self) {
+        let Some(project) = self
+            .selected_project_index()
+            .and_then(|i| self.config.projects.get_mut(i))
+        else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        if project.presets.is_empty() {
+            self.flash_message = Some("No presets yet. Press S to create one.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        let next = match project
+            .active_preset
+            .as_ref()
+            .and_then(|name| project.presets.iter().position(|p| &p.name == name))
+        {
+            None => Some(0),
+            Some(i) if i + 1 < project.presets.len() => Some(i + 1),
+            Some(_) => None,
+        };
+        project.active_preset = next.map(|i| project.presets[i].name.clone());
+        let message = match &project.active_preset {
+            Some(name) => format!("Preset: {}", name),
+            None => "Preset: none".to_string(),
+        };
+        self.save_config();
+        self.flash_message = Some(message);
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Open the preset list for the selected project.
+    pub fn open_presets(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        let selected = self
+            .config
+            .projects
+            .get(project_index)
+            .and_then(|p| {
+                p.active_preset
+                    .as_ref()
+                    .and_then(|name| p.presets.iter().position(|x| &x.name == name))
+            })
+            .unwrap_or(0);
+        self.dialog = Some(DialogKind::Presets {
+            project_index,
+            selected,
+        });
+    }
+
+    /// Create a preset from the project's current settings, make it active and open it for editing.
+    fn create_preset(&mut self, project_index: usize, name: String) {
+        let Some(project) = self.config.projects.get_mut(project_index) else {
+            return;
+        };
+        if project.presets.iter().any(|p| p.name == name) {
+            self.flash_message = Some(format!("A preset named \"{}\" already exists.", name));
+            self.flash_until = self.tick + 90;
+            self.dialog = Some(DialogKind::Presets {
+                project_index,
+                selected: 0,
+            });
+            return;
+        }
+        let settings = project.build_settings(None);
+        // Project-level arguments and environment already apply to every build.
+        let (extra_args, env) = project
+            .active_preset()
+            .map(|p| (p.extra_args.clone(), p.env.clone()))
+            .unwrap_or_default();
+        project.presets.push(BuildPreset {
+            name: name.clone(),
+            target: settings.target,
+            platform: settings.platform,
+            configuration: settings.configuration,
+            mode: settings.mode,
+            extra_args,
+            env,
+            command: settings.command,
+        });
+        project.active_preset = Some(name);
+        let preset_index = project.presets.len() - 1;
+        self.save_config();
+        self.dialog = Some(DialogKind::PresetEditor {
+            project_index,
+            preset_index,
+            selected: 0,
+        });
+    }
+
+    /// Make the preset at `preset_index` the one builds use.
+    pub fn activate_preset(&mut self, project_index: usize, preset_index: usize) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            if let Some(preset) = project.presets.get(preset_index) {
+                project.active_preset = Some(preset.name.clone());
+                self.flash_message = Some(format!("Preset: {}", preset.name));
+                self.flash_until = self.tick + 60;
+                self.save_config();
+            }
+        }
+    }
+
+    fn delete_preset(&mut self, project_index: usize, preset_index: usize) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            if preset_index < project.presets.len() {
+                let removed = project.presets.remove(preset_index);
+                if project.active_preset.as_ref() == Some(&removed.name) {
+                    project.active_preset = None;
+                }
+                self.save_config();
+            }
+        }
+        self.dialog = Some(DialogKind::Presets {
+            project_index,
+            selected: preset_index.saturating_sub(1),
+        });
+    }
+
+    /// Cycle or edit the focused field of the preset editor.
+    pub fn activate_preset_field(&mut self) {
+        let Some(DialogKind::PresetEditor {
+            project_index,
+            preset_index,
+            selected,
+        }) = self.dialog.clone()
+        else {
+            return;
+        };
+        let Some(preset) = self
+            .config
+            .projects
+            .get_mut(project_index)
+            .and_then(|p| p.presets.get_mut(preset_index))
+        else {
+            return;
+        };
+
+        let (label, value) = match selected {
+            0 => ("Preset Name", preset.name.clone()),
+            1 => (
+                "Preset Target (empty for project default)",
+                preset.target.clone().unwrap_or_default(),
+            ),
+            2 => {
+                preset.platform = preset.platform.next();
+                self.save_config();
+                return;
+            }
+            3 => {
+                preset.configuration = preset.configuration.next();
+                self.save_config();
+                return;
+            }
+            4 => {
+                preset.mode = preset.mode.next();
+                self.save_config();
+                return;
+            }
+            5 => (
+                "Extra Arguments (space separated)",
+                preset.extra_args.join(" "),
+            ),
+            6 => (
+                "Environment (KEY=VALUE; KEY2=VALUE2)",
+                format_env(&preset.env),
+            ),
+            7 => (
+                "Command run instead of UBT / RunUAT (empty for none)",
+                preset.command.clone().unwrap_or_default(),
+            ),
+            _ => return,
+        };
+        self.dialog = Some(DialogKind::PathInput {
+            label: label.into(),
+            value,
+            target: PathInputTarget::PresetField(project_index, preset_index, selected),
+        });
+    }
+
+    /// Apply a text field edited in the preset editor, then return to the editor.
+    fn set_preset_field(
+        &mut self,
+        project_index: usize,
+        preset_index: usize,
+        field: usize,
+        value: String,
+    ) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            let name_taken = project
+                .presets
+                .iter()
+                .enumerate()
+                .any(|(i, p)| i != preset_index && p.name == value);
+            if let Some(preset) = project.presets.get_mut(preset_index) {
+                match field {
+                    0 if value.is_empty() || name_taken => {
+                        self.flash_message = Some("Preset names must be unique.".into());
+                        self.flash_until = self.tick + 90;
+                    }
+                    0 => {
+                        if project.active_preset.as_ref() == Some(&preset.name) {
+                            project.active_preset = Some(value.clone());
+                        }
+                        preset.name = value;
+                    }
+                    1 => preset.target = Some(value).filter(|v| !v.is_empty()),
+                    5 => preset.extra_args = value.split_whitespace().map(String::from).collect(),
+                    6 => preset.env = parse_env(&value),
+                    7 => preset.command = Some(value).filter(|v| !v.trim().is_empty()),
+                    _ => {}
+                }
+                self.save_config();
+            }
+        }
+        self.dialog = Some(DialogKind::PresetEditor {
+            project_index,
+            preset_index,
+            selected: field,
+        });
+    }
+
+    /// Open a text input for the selected project's extra UBT arguments.
+    pub fn open_extra_args_dialog(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        self.dialog = Some(DialogKind::PathInput {
+            label: "Extra UBT Arguments (space separated)".into(),
+            value: self.config.projects[project_index].extra_args.join(" "),
+            target: PathInputTarget::SetExtraArgs(project_index),
+        });
+    }
+
+    /// Open a text input for the selected project's build environment variables.
+    pub fn open_env_dialog(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        self.dialog = Some(DialogKind::PathInput {
+            label: "Build Environment (KEY=VALUE; KEY2=VALUE2)".into(),
+            value: format_env(&self.config.projects[project_index].env),
+            target: PathInputTarget::SetEnv(project_index),
+        });
+    }
+
+    /// Open the pre/post-build hook list for the selected project.
+    pub fn open_hooks(&mut self) {
+        match self.selected_project_index() {
+            Some(project_index) => {
+                self.dialog = Some(DialogKind::Hooks {
+                    project_index,
+                    selected: 0,
+                });
+            }
+            None => {
+                self.flash_message = Some("No project selected.".into());
+                self.flash_until = self.tick + 60;
+            }
+        }
+    }
+
+    /// Map a row of the hooks dialog to its stage and index within that stage.
+    pub fn hook_at(&self, project_index: usize, row: usize) -> Option<(HookStage, usize)> {
+        let project = self.config.projects.get(project_index)?;
+        let pre = project.pre_build_hooks.len();
+        if row < pre {
+            Some((HookStage::Pre, row))
+        } else if row < pre + project.post_build_hooks.len() {
+            Some((HookStage::Post, row - pre))
+        } else {
+            None
+        }
+    }
+
+    fn hooks_mut(&mut self, project_index: usize, stage: HookStage) -> Option<&mut Vec<String>> {
+        let project = self.config.projects.get_mut(project_index)?;
+        Some(match stage {
+            HookStage::Pre => &mut project.pre_build_hooks,
+            HookStage::Post => &mut project.post_build_hooks,
+        })
+    }
+
+    /// Remove the hook on `row` of the hooks dialog.
+    pub fn remove_hook(&mut self, project_index: usize, row: usize) {
+        if let Some((stage, index)) = self.hook_at(project_index, row) {
+            if let Some(hooks) = self.hooks_mut(project_index, stage) {
+                hooks.remove(index);
+                self.save_config();
+            }
+        }
+    }
+
+    /// Move the hook on `row` up or down within its stage. Returns its new row.
+    pub fn move_hook(&mut self, project_index: usize, row: usize, up: bool) -> usize {
+        let Some((stage, index)) = self.hook_at(project_index, row) else {
+            return row;
+        };
+        let Some(hooks) = self.hooks_mut(project_index, stage) else {
+            return row;
+        };
+        let other = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < hooks.len())
+        };
+        let Some(other) = other else {
+            return row;
+        };
+        hooks.swap(index, other);
+        self.save_config();
+        if up {
+            row - 1
+        } else {
+            row + 1
+        }
+    }
+
+    /// Open a text input to edit the hook on `row` of the hooks dialog.
+    pub fn edit_hook(&mut self, project_index: usize, row: usize) {
+        let Some((stage, index)) = self.hook_at(project_index, row) else {
+            return;
+        };
+        let value = self
+            .hooks_mut(project_index, stage)
+            .and_then(|hooks| hooks.get(index).cloned())
+            .unwrap_or_default();
+        self.dialog = Some(DialogKind::PathInput {
+            label: hook_input_label(stage).into(),
+            value,
+            target: PathInputTarget::EditHook(project_index, stage, index),
+        });
+    }
+
+    /// Open a text input for a new hook appended to `stage`.
+    pub fn add_hook(&mut self, project_index: usize, stage: HookStage) {
+        self.dialog = Some(DialogKind::PathInput {
+            label: hook_input_label(stage).into(),
+            value: String::new(),
+            target: PathInputTarget::AddHook(project_index, stage),
+        });
+    }
+
+    /// Store an added or edited hook command, then return to the hooks dialog.
+    fn save_hook(
+        &mut self,
+        project_index: usize,
+        stage: HookStage,
+        index: Option<usize>,
+        command: String,
+    ) {
+        if let Some(hooks) = self.hooks_mut(project_index, stage) {
+            match index {
+                _ if command.is_empty() => {}
+                Some(i) if i < hooks.len() => hooks[i] = command,
+                _ => hooks.push(command),
+            }
+            self.save_config();
+        }
+        self.dialog = Some(DialogKind::Hooks {
+            project_index,
+            selected: 0,
+        });
+    }
+
+    /// Open the package options dialog for the selected project.
+    pub fn open_package_options(&mut self) {
+        match self.selected_project_index() {
+            Some(project_index) => {
+                self.dialog = Some(DialogKind::PackageOptions {
+                    project_index,
+                    selected: 0,
+                });
+            }
+            None => {
+                self.flash_message = Some("No project selected.".into());
+                self.flash_until = self.tick + 60;
+            }
+        }
+    }
+
+    /// Toggle or edit the focused row of the package options dialog.
+    pub fn activate_package_option(&mut self) {
+        let Some(DialogKind::PackageOptions {
+            project_index,
+            selected,
+        }) = self.dialog.clone()
+        else {
+            return;
+        };
+        let Some(package) = self
+            .config
+            .projects
+            .get(project_index)
+            .map(|p| p.package.clone())
+        else {
+            return;
+        };
+
+        match selected {
+            0 => self.edit_package_settings(project_index, |p| p.pak = !p.pak),
+            1 => self.edit_package_settings(project_index, |p| p.stage = !p.stage),
+            2 => self.edit_package_settings(project_index, |p| p.archive = !p.archive),
+            3 => {
+                self.dialog = Some(DialogKind::PathInput {
+                    label: "Cook Flavour (e.g. ASTC, empty for none)".into(),
+                    value: package.cook_flavor.unwrap_or_default(),
+                    target: PathInputTarget::SetCookFlavor(project_index),
+                });
+            }
+            4 => {
+                self.dialog = Some(DialogKind::PathInput {
+                    label: "Package Output Directory (empty for default)".into(),
+                    value: package.output_dir.unwrap_or_default(),
+                    target: PathInputTarget::SetPackageOutputDir(project_index),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn edit_package_settings(
+        &mut self,
+        project_index: usize,
+        edit: impl FnOnce(&mut PackageSettings),
+    ) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            edit(&mut project.package);
+            self.save_config();
+        }
+    }
+
     pub fn set_engine_path(&mut self, path: String) {
         self.config.unreal_engine_path = Some(path);
         self.save_config();
@@ -280,41 +897,272 @@ impl App {
         self.logs.clear();
         self.log_scroll = 0;
         self.auto_scroll_logs = true;
+        self.diagnostics.clear();
+        self.problem_selected = None;
+        if self.focus == FocusItem::Problems {
+            self.focus = self.projects_anchor_item();
+        }
     }
 
     pub fn push_log(&mut self, text: String) {
-        let text = sanitize_log_text(&text);
-        if text.is_empty() {
+        let Some(mut line) = make_log_line(&text) else {
             return;
+        };
+        if let Some(diagnostic) = crate::diagnostics::parse_line(&line.text, self.logs.len()) {
+            line.level = match diagnostic.severity {
+                Severity::Error => LogLevel::Error,
+                Severity::Warning => LogLevel::Warning,
+            };
+            let duplicate = crate::diagnostics::is_duplicate(
+                &self.diagnostics,
+                self.build_log_start,
+                &diagnostic,
+            );
+            if !duplicate {
+                self.diagnostics.push(diagnostic);
+            }
         }
-        let level = classify_log_line(&text);
-        self.logs.push(LogLine { text, level });
+        self.logs.push(line);
         if self.logs.len() > 10_000 {
+            let selected = self.selected_problem().map(|d| d.log_index);
             self.logs.drain(0..1000);
             self.log_scroll = self.log_scroll.saturating_sub(1000);
+            self.build_log_dropped += 1000usize.saturating_sub(self.build_log_start);
+            self.build_log_start = self.build_log_start.saturating_sub(1000);
+            // Diagnostics of the dropped lines go with them.
+            self.diagnostics.retain(|d| d.log_index >= 1000);
+            for diagnostic in &mut self.diagnostics {
+                diagnostic.log_index -= 1000;
+            }
+            self.problem_selected = selected
+                .and_then(|log_index| log_index.checked_sub(1000))
+                .and_then(|log_index| {
+                    self.problem_order()
+                        .iter()
+                        .position(|&i| self.diagnostics[i].log_index == log_index)
+                });
         }
         if self.auto_scroll_logs {
             self.log_scroll = self.logs.len().saturating_sub(1);
         }
     }
 
+    /// Diagnostics grouped by file, in order of first appearance. Diagnostics without
+    /// a file (UBT messages) are grouped under None.
+    pub fn problem_groups(&self) -> Vec<(Option<&str>, Vec<usize>)> {
+        let mut groups: Vec<(Option<&str>, Vec<usize>)> = Vec::new();
+        for (i, diagnostic) in self.diagnostics.iter().enumerate() {
+            let file = diagnostic.file.as_deref();
+            match groups.iter_mut().find(|(f, _)| *f == file) {
+                Some((_, indices)) => indices.push(i),
+                None => groups.push((file, vec![i])),
+            }
+        }
+        groups
+    }
+
+    /// Diagnostic indices in the order the problems panel lists them.
+    pub fn problem_order(&self) -> Vec<usize> {
+        self.problem_groups()
+            .into_iter()
+            .flat_map(|(_, indices)| indices)
+            .collect()
+    }
+
+    /// The diagnostic selected in the problems panel.
+    pub fn selected_problem(&self) -> Option<&Diagnostic> {
+        let order = self.problem_order();
+        order
+            .get(self.problem_selected?)
+            .and_then(|&i| self.diagnostics.get(i))
+    }
+
+    /// Select a row of the problems panel and scroll the build log to its line.
+    pub fn select_problem(&mut self, row: usize) {
+        let order = self.problem_order();
+        let Some(&index) = order.get(row) else {
+            return;
+        };
+        self.problem_selected = Some(row);
+        let log_index = self.diagnostics[index].log_index;
+        self.log_tab = 0;
+        self.auto_scroll_logs = false;
+        // log_scroll is the bottom edge of the view; leave a few lines of context below.
+        self.log_scroll = (log_index + 5).min(self.logs.len().saturating_sub(1));
+    }
+
+    /// Jump to the next (or previous) error, wrapping around. Falls back to warnings
+    /// when the build produced no errors.
+    pub fn jump_to_problem(&mut self, forward: bool) {
+        let order = self.problem_order();
+        if order.is_empty() {
+            self.flash_message = Some("No problems in the build log.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        let has_errors = self
+            .diagnostics
+            .iter()
+            .any(|d| d.severity == Severity::Error);
+        let candidates: Vec<usize> = (0..order.len())
+            .filter(|&row| !has_errors || self.diagnostics[order[row]].severity == Severity::Error)
+            .collect();
+        let next = match (self.problem_selected, forward) {
+            (None, true) => candidates.first(),
+            (None, false) => candidates.last(),
+            (Some(current), true) => candidates
+                .iter()
+                .find(|&&row| row > current)
+                .or(candidates.first()),
+            (Some(current), false) => candidates
+                .iter()
+                .rev()
+                .find(|&&row| row < current)
+                .or(candidates.last()),
+        };
+        if let Some(&row) = next {
+            self.select_problem(row);
+        }
+    }
+
+    /// Open the selected diagnostic (or the first error) in the configured editor.
+    pub fn open_selected_problem(&mut self) {
+        let diagnostic = self
+            .selected_problem()
+            .or_else(|| {
+                self.diagnostics
+                    .iter()
+                    .find(|d| d.severity == Severity::Error)
+            })
+            .cloned();
+        match diagnostic {
+            Some(Diagnostic {
+                file: Some(file),
+                line,
+                column,
+                ..
+            }) => self.open_in_editor(&file, line.unwrap_or(1), column.unwrap_or(1)),
+            Some(_) => {
+                self.flash_message = Some("This problem has no source location.".into());
+                self.flash_until = self.tick + 60;
+            }
+            None => {
+                self.flash_message = Some("No problems in the build log.".into());
+                self.flash_until = self.tick + 60;
+            }
+        }
+    }
+
+    /// Open the source location mentioned on the log line at the cursor, looking a few
+    /// lines up when that line has none.
+    pub fn open_log_location(&mut self) {
+        let logs = self.visible_logs();
+        let cursor = self.log_scroll.min(logs.len().saturating_sub(1));
+        let location = logs[..logs.len().min(cursor + 1)]
+            .iter()
+            .rev()
+            .take(20)
+            .find_map(|l| crate::editor::find_location(&l.text));
+        match location {
+            Some((file, line, column)) => self.open_in_editor(&file, line, column),
+            None => {
+                self.flash_message = Some("No file:line location near the log cursor.".into());
+                self.flash_until = self.tick + 60;
+            }
+        }
+    }
+
+    /// Open `file` at `line`/`column` with the editor command from the config.
+    /// Relative paths are resolved against the selected project's directory.
+    fn open_in_editor(&mut self, file: &str, line: u32, column: u32) {
+        let mut path = PathBuf::from(file);
+        if path.is_relative() {
+            if let Some(dir) = self
+                .selected_project()
+                .and_then(|p| PathBuf::from(&p.path).parent().map(|d| d.to_path_buf()))
+            {
+                path = dir.join(path);
+            }
+        }
+        let file = path.to_string_lossy().to_string();
+        let template = self
+            .config
+            .editor_command
+            .clone()
+            .unwrap_or_else(crate::editor::default_template);
+        let command = match crate::editor::build_command(&template, &file, line, column) {
+            Ok(command) => command,
+            Err(e) => {
+                self.flash_message = Some(e);
+                self.flash_until = self.tick + 90;
+                return;
+            }
+        };
+        self.flash_message = Some(format!("Opening {}:{}", file, line));
+        self.flash_until = self.tick + 60;
+        if command.terminal {
+            self.pending_terminal_command = Some(command);
+            return;
+        }
+        let mut cmd = std::process::Command::new(&command.program);
+        cmd.args(&command.args);
+        if let Err(e) = crate::launch::spawn_detached(&mut cmd) {
+            self.flash_message = Some(format!("Failed to run {}: {}", command.program, e));
+            self.flash_until = self.tick + 90;
+        }
+    }
+
+    pub fn open_editor_command_dialog(&mut self) {
+        self.dialog = Some(DialogKind::PathInput {
+            label: "Editor Command ({file} {line} {column}; empty for $VISUAL/$EDITOR)".into(),
+            value: self
+                .config
+                .editor_command
+                .clone()
+                .unwrap_or_else(crate::editor::default_template),
+            target: PathInputTarget::SetEditorCommand,
+        });
+    }
+
     /// Returns the list of available build action labels based on current state.
     pub fn available_build_actions(&self) -> Vec<&'static str> {
         let mut actions = Vec::new();
         match self.build_state {
             BuildState::Running => {
                 actions.push("Cancel");
+                actions.push("Build");
+                actions.push("Clean Rebuild");
+                actions.push("Package");
+                actions.push("Project Files");
             }
             BuildState::Idle => {
                 actions.push("Build");
                 actions.push("Clean Rebuild");
+                actions.push("Package");
+                actions.push("Project Files");
+                actions.push("Editor");
+                actions.push("Game");
             }
             _ => {
                 actions.push("Build");
                 actions.push("Clean Rebuild");
+                actions.push("Package");
+                actions.push("Project Files");
+                actions.push("Editor");
+                actions.push("Game");
                 actions.push("Clear");
             }
         }
+        if !self.queue.is_empty() {
+            actions.push("Queue");
+        }
+        if self
+            .launch_session
+            .as_ref()
+            .is_some_and(|s| s.running_count() > 0)
+        {
+            actions.push("Stop All");
+        }
         if !self.logs.is_empty() {
             actions.push("Copy Log");
         }
@@ -328,6 +1176,12 @@ impl App {
             match label {
                 "Build" => self.start_build(),
                 "Clean Rebuild" => self.start_clean_rebuild(),
+                "Package" => self.start_package(),
+                "Project Files" => self.open_project_files_picker(),
+                "Editor" => self.launch_selected(LaunchMode::Editor),
+                "Game" => self.launch_selected(LaunchMode::Game),
+                "Queue" => self.open_build_queue(),
+                "Stop All" => self.stop_launch_session(),
                 "Cancel" => self.cancel_build(),
                 "Clear" => {
                     self.clear_logs();
@@ -341,13 +1195,13 @@ impl App {
     }
 
     pub fn copy_logs(&mut self) {
-        if self.logs.is_empty() {
+        if self.visible_logs().is_empty() {
             self.flash_message = Some("No logs to copy.".into());
             self.flash_until = self.tick + 60;
             return;
         }
         let text: String = self
-            .logs
+            .visible_logs()
             .iter()
             .map(|l| l.text.as_str())
             .collect::<Vec<_>>()
@@ -364,47 +1218,148 @@ impl App {
     }
 
     pub fn start_build(&mut self) {
-        self.start_build_with_mode(BuildMode::Standard);
+        self.start_build_with_mode(None);
     }
 
     pub fn start_clean_rebuild(&mut self) {
-        self.start_build_with_mode(BuildMode::CleanRebuild);
+        self.start_build_with_mode(Some(BuildMode::CleanRebuild));
     }
 
-    fn start_build_with_mode(&mut self, mode: BuildMode) {
-        let project = match self.selected_project() {
-            Some(p) => p.clone(),
-            None => {
-                self.push_log(
-                    "No project selected. Select one in Projects and press Enter.".into(),
-                );
-                return;
+    pub fn start_package(&mut self) {
+        self.start_build_with_mode(Some(BuildMode::Package));
+    }
+
+    pub fn start_generate_project_files(&mut self) {
+        self.start_build_with_mode(Some(BuildMode::GenerateProjectFiles));
+    }
+
+    /// Open the project file generator picker for the selected project, on its current choice.
+    pub fn open_project_files_picker(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        let selected = self.config.projects[project_index]
+            .project_file_format
+            .and_then(|f| ProjectFileFormat::ALL.iter().position(|a| *a == f))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.dialog = Some(DialogKind::ProjectFilesPicker {
+            project_index,
+            selected,
+        });
+    }
+
+    /// Remember the picked generator for the project and generate its project files.
+    fn pick_project_file_format(&mut self, project_index: usize, selected: usize) {
+        let format = selected
+            .checked_sub(1)
+            .and_then(|i| ProjectFileFormat::ALL.get(i).copied());
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            project.project_file_format = format;
+            self.save_config();
+        }
+        self.start_generate_project_files();
+    }
+
+    /// Enqueue a job for the selected project; it starts right away if nothing is running.
+    /// `mode` overrides the active preset's build mode (None runs the preset as-is).
+    fn start_build_with_mode(&mut self, mode: Option<BuildMode>) {
+        if self.restore_rx.is_some() {
+            self.flash_message = Some("Wait for the restore to finish before building.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        let project = match self.selected_project() {
+            Some(p) => p.clone(),
+            None => {
+                self.push_log(
+                    "No project selected. Select one in Projects and press Enter.".into(),
+                );
+                return;
             }
         };
+        if self.config.unreal_engine_path.is_none() {
+            self.push_log("No engine path set.".into());
+            return;
+        }
+
+        let job = QueuedJob::new(&project, mode);
+        self.queue_paused = false;
+        if self.build_state == BuildState::Running {
+            self.flash_message = Some(format!("Queued: {}", job.label()));
+            self.flash_until = self.tick + 60;
+            self.queue.push(job);
+        } else {
+            self.queue.push(job);
+            self.start_next_job(true);
+        }
+    }
+
+    /// Start the first pending job in the queue. Returns false if there was none.
+    fn start_next_job(&mut self, fresh_log: bool) -> bool {
+        let Some(index) = self
+            .queue
+            .iter()
+            .position(|j| j.status == JobStatus::Pending)
+        else {
+            return false;
+        };
+        let job = self.queue[index].clone();
         let engine_path = match &self.config.unreal_engine_path {
             Some(p) => p.clone(),
             None => {
                 self.push_log("No engine path set.".into());
-                return;
+                self.queue_paused = true;
+                return false;
             }
         };
 
-        self.clear_logs();
+        if fresh_log {
+            self.clear_logs();
+        } else {
+            self.push_log(format!("──── Next in queue: {} ────", job.label()));
+        }
+        self.build_log_start = self.logs.len().saturating_sub(1);
+        self.build_log_dropped = 0;
+        self.build_progress = None;
+        self.build_cancelling = false;
+        self.build_phase = None;
+        self.failed_phase = None;
+        self.action_times.clear();
+        self.queue[index].status = JobStatus::Running;
         self.build_state = BuildState::Running;
         self.auto_scroll_logs = true;
+        let label = format!("{} {}", job.settings.configuration, job.settings.platform);
+        self.build_label = Some(match job.settings.mode {
+            BuildMode::Package => format!("Package {}", label),
+            BuildMode::GenerateProjectFiles => match job.settings.project_file_format {
+                Some(format) => format!("Project Files ({})", format),
+                None => "Project Files".to_string(),
+            },
+            _ => label,
+        });
 
         let (tx, rx) = mpsc::unbounded_channel();
         self.log_rx = Some(rx);
 
-        match crate::build::spawn_build(
-            project.path.clone(),
-            engine_path,
-            project.editor_target.clone(),
-            tx,
-            mode,
-        ) {
+        let started =
+            crate::backend::for_settings(&engine_path, &job.settings).and_then(|backend| {
+                crate::build::spawn_build(
+                    job.project_path.clone(),
+                    engine_path,
+                    job.settings.clone(),
+                    backend,
+                    self.config.clean_backup_max_age_days(),
+                    self.config.cancel_timeout(),
+                    tx,
+                )
+            });
+        match started {
             Ok(handle) => {
                 self.build_handle = Some(handle);
+                self.build_started = Some(chrono::Local::now());
             }
             Err(e) => {
                 self.push_log(format!("Failed to start build: {}", e));
@@ -413,31 +1368,1000 @@ impl App {
                         "Multiple editor targets were detected. Choose one and build again.",
                     );
                 }
+                self.queue[index].status = JobStatus::Failed;
                 self.build_state = BuildState::Error;
                 self.log_rx = None;
             }
         }
+        true
     }
 
-    pub fn cancel_build(&mut self) {
+    /// Open the clean dialog for the selected project and start measuring folder sizes.
+    pub fn open_clean_dialog(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        let Some(project_dir) = self
+            .config
+            .projects
+            .get(project_index)
+            .and_then(|p| PathBuf::from(&p.path).parent().map(PathBuf::from))
+        else {
+            return;
+        };
+
+        let paths = crate::build::clean_candidates(&project_dir);
+        self.clean_candidates = paths
+            .iter()
+            .map(|path| CleanCandidate {
+                path: path.clone(),
+                size: None,
+            })
+            .collect();
+        self.clean_path_sizes.clear();
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.clean_size_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            for (i, path) in paths.iter().enumerate() {
+                let sizes = crate::build::resolve_clean_dir(&project_dir, path)
+                    .into_iter()
+                    .map(|dir| {
+                        let size = crate::build::dir_size(&dir);
+                        (dir, size)
+                    })
+                    .collect();
+                if tx.send((i, sizes)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.dialog = Some(DialogKind::Clean {
+            project_index,
+            selected: 0,
+        });
+    }
+
+    /// Drain folder sizes measured for the clean dialog.
+    pub fn poll_clean_sizes(&mut self) {
+        let Some(rx) = &mut self.clean_size_rx else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok((i, sizes)) => {
+                    if let Some(candidate) = self.clean_candidates.get_mut(i) {
+                        candidate.size = Some(sizes.iter().map(|(_, size)| size).sum());
+                    }
+                    self.clean_path_sizes.extend(sizes);
+                }
+                Err(mpsc::error::TryRecvError::Empty) => break,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.clean_size_rx = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Tick or untick a folder of the clean dialog; the choice is saved with the project.
+    pub fn toggle_clean_dir(&mut self, project_index: usize, row: usize) {
+        let Some(path) = self.clean_candidates.get(row).map(|c| c.path.clone()) else {
+            return;
+        };
+        let Some(project) = self.config.projects.get_mut(project_index) else {
+            return;
+        };
+        let mut dirs = project.clean_dirs();
+        match dirs.iter().position(|d| *d == path) {
+            Some(i) => {
+                dirs.remove(i);
+            }
+            None => dirs.push(path),
+        }
+        project.clean_dirs = Some(dirs);
+        self.save_config();
+    }
+
+    /// Switch a project's clean rebuilds between deleting folders and moving them into a backup.
+    pub fn toggle_clean_backup(&mut self, project_index: usize) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            project.clean_backup = !project.clean_backup;
+            self.save_config();
+        }
+    }
+
+    /// Move the folders of the project's most recent clean backup back into place.
+    pub fn restore_last_clean(&mut self, project_index: usize) {
+        if self.build_state == BuildState::Running {
+            self.flash_message = Some("Wait for the build to finish before restoring.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        let Some(project_dir) = self
+            .config
+            .projects
+            .get(project_index)
+            .and_then(|p| PathBuf::from(&p.path).parent().map(PathBuf::from))
+        else {
+            return;
+        };
+
+        if self.restore_rx.is_some() {
+            self.flash_message = Some("A restore is already running.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+
+        // Moving whole Binaries / Intermediate trees back can take a while.
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.restore_rx = Some(rx);
+        tokio::task::spawn_blocking(move || {
+            let _ = tx.send(crate::build::restore_last_clean(&project_dir));
+        });
+        self.push_log("Restoring the last clean backup...".into());
+    }
+
+    /// Report a finished clean backup restore.
+    pub fn poll_restore(&mut self) {
+        let Some(rx) = &mut self.restore_rx else {
+            return;
+        };
+        let result = match rx.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::error::TryRecvError::Empty) => return,
+            Err(mpsc::error::TryRecvError::Disconnected) => {
+                Err("The restore stopped unexpectedly.".to_string())
+            }
+        };
+        self.restore_rx = None;
+
+        match result {
+            Ok(restored) => {
+                for path in &restored {
+                    self.push_log(format!("Restored: {}", path));
+                }
+                self.push_log(format!(
+                    "Restored {} paths from the last clean backup.",
+                    restored.len()
+                ));
+                self.flash_message = Some("Last clean restored.".into());
+            }
+            Err(e) => {
+                self.push_log(e.clone());
+                self.flash_message = Some(e);
+            }
+        }
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Log what a clean rebuild of the project would remove, without touching anything.
+    pub fn clean_dry_run(&mut self, project_index: usize) {
+        let Some(project) = self.config.projects.get(project_index) else {
+            return;
+        };
+        let Some(project_dir) = PathBuf::from(&project.path).parent().map(PathBuf::from) else {
+            return;
+        };
+        let verb = if project.clean_backup {
+            "move to a backup"
+        } else {
+            "remove"
+        };
+        let header = format!("Dry run: clean rebuild of {} would {}:", project.name, verb);
+        // The same list the real clean works through.
+        let paths = crate::build::clean_paths(&project.path, &project_dir, &project.clean_dirs());
+
+        self.push_log(header);
+        if paths.is_empty() {
+            self.push_log("  Nothing: no selected folder exists.".into());
+            return;
+        }
+        let mut total = 0;
+        let mut pending = false;
+        for path in &paths {
+            let size = if path.is_file() {
+                Some(crate::build::dir_size(path))
+            } else {
+                self.clean_path_sizes.get(path).copied()
+            };
+            let size = match size {
+                Some(size) => {
+                    total += size;
+                    format_size(size)
+                }
+                None => {
+                    pending = true;
+                    "size pending".to_string()
+                }
+            };
+            let relative = path.strip_prefix(&project_dir).unwrap_or(path);
+            self.push_log(format!("  {} ({})", relative.display(), size));
+        }
+        self.push_log(format!(
+            "Total: {}{}",
+            format_size(total),
+            if pending { " so far" } else { "" }
+        ));
+    }
+
+    /// Launch the selected project in the editor or as a standalone game.
+    pub fn launch_selected(&mut self, mode: LaunchMode) {
+        let Some(project_path) = self.selected_project().map(|p| p.path.clone()) else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        self.launch_project(&project_path, mode);
+    }
+
+    fn launch_project(&mut self, project_path: &str, mode: LaunchMode) {
+        let Some(engine_path) = self.config.unreal_engine_path.clone() else {
+            self.push_log("No engine path set.".into());
+            return;
+        };
+        match crate::launch::launch_project(&engine_path, project_path, mode) {
+            Ok(command) => {
+                self.push_log(format!("Launched {}: {}", mode, command));
+                self.flash_message = Some(format!("Launched {}", mode));
+            }
+            Err(e) => {
+                self.push_log(e.clone());
+                self.flash_message = Some(e);
+            }
+        }
+        self.flash_until = self.tick + 60;
+    }
+
+    pub fn open_launch_profiles(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        self.dialog = Some(DialogKind::LaunchProfiles {
+            project_index,
+            selected: 0,
+        });
+    }
+
+    /// Add a launch profile with default settings and open it for editing.
+    fn create_launch_profile(&mut self, project_index: usize, name: String) {
+        let Some(project) = self.config.projects.get_mut(project_index) else {
+            return;
+        };
+        if project.launch_profiles.iter().any(|p| p.name == name) {
+            self.flash_message = Some(format!("A profile named \"{}\" already exists.", name));
+            self.flash_until = self.tick + 90;
+            self.dialog = Some(DialogKind::LaunchProfiles {
+                project_index,
+                selected: 0,
+            });
+            return;
+        }
+        project.launch_profiles.push(LaunchProfile::new(name));
+        let profile_index = project.launch_profiles.len() - 1;
+        self.save_config();
+        self.dialog = Some(DialogKind::LaunchProfileEditor {
+            project_index,
+            profile_index,
+            selected: 0,
+        });
+    }
+
+    fn delete_launch_profile(&mut self, project_index: usize, profile_index: usize) {
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            if profile_index < project.launch_profiles.len() {
+                project.launch_profiles.remove(profile_index);
+                self.save_config();
+            }
+        }
+        self.dialog = Some(DialogKind::LaunchProfiles {
+            project_index,
+            selected: profile_index.saturating_sub(1),
+        });
+    }
+
+    /// Cycle, toggle or edit the focused field of the launch profile editor.
+    pub fn activate_launch_profile_field(&mut self) {
+        let Some(DialogKind::LaunchProfileEditor {
+            project_index,
+            profile_index,
+            selected,
+        }) = self.dialog.clone()
+        else {
+            return;
+        };
+        let Some(profile) = self
+            .config
+            .projects
+            .get_mut(project_index)
+            .and_then(|p| p.launch_profiles.get_mut(profile_index))
+        else {
+            return;
+        };
+
+        let (label, value) = match selected {
+            0 => ("Profile Name", profile.name.clone()),
+            1 => (
+                "Map (e.g. /Game/Maps/Arena, empty for default)",
+                profile.map.clone(),
+            ),
+            2 => {
+                profile.server = profile.server.next();
+                self.save_config();
+                return;
+            }
+            3 => ("Number of Clients", profile.clients.to_string()),
+            4 => ("Server Port", profile.port.to_string()),
+            5 => {
+                profile.log = !profile.log;
+                self.save_config();
+                return;
+            }
+            6 => {
+                profile.windowed = !profile.windowed;
+                self.save_config();
+                return;
+            }
+            7 => (
+                "Resolution (WIDTHxHEIGHT, empty for default)",
+                profile.resolution.clone().unwrap_or_default(),
+            ),
+            8 => (
+                "Extra Arguments (space separated)",
+                profile.extra_args.join(" "),
+            ),
+            _ => return,
+        };
+        self.dialog = Some(DialogKind::PathInput {
+            label: label.into(),
+            value,
+            target: PathInputTarget::LaunchProfileField(project_index, profile_index, selected),
+        });
+    }
+
+    /// Apply a text field edited in the launch profile editor, then return to the editor.
+    fn set_launch_profile_field(
+        &mut self,
+        project_index: usize,
+        profile_index: usize,
+        field: usize,
+        value: String,
+    ) {
+        let mut error = None;
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            let name_taken = project
+                .launch_profiles
+                .iter()
+                .enumerate()
+                .any(|(i, p)| i != profile_index && p.name == value);
+            if let Some(profile) = project.launch_profiles.get_mut(profile_index) {
+                match field {
+                    0 if value.is_empty() || name_taken => {
+                        error = Some("Profile names must be unique.");
+                    }
+                    0 => profile.name = value,
+                    1 => profile.map = value,
+                    3 => match value.parse() {
+                        Ok(clients) => profile.clients = clients,
+                        Err(_) => error = Some("Clients must be a whole number."),
+                    },
+                    4 => match value.parse() {
+                        Ok(port) => profile.port = port,
+                        Err(_) => error = Some("Port must be a number from 0 to 65535."),
+                    },
+                    7 => {
+                        let previous = profile.resolution.take();
+                        profile.resolution = Some(value).filter(|v| !v.is_empty());
+                        if profile.resolution.is_some() && profile.resolution_size().is_none() {
+                            profile.resolution = previous;
+                            error = Some("Resolution must look like 1280x720.");
+                        }
+                    }
+                    8 => profile.extra_args = value.split_whitespace().map(String::from).collect(),
+                    _ => {}
+                }
+                self.save_config();
+            }
+        }
+        if let Some(error) = error {
+            self.flash_message = Some(error.into());
+            self.flash_until = self.tick + 90;
+        }
+        self.dialog = Some(DialogKind::LaunchProfileEditor {
+            project_index,
+            profile_index,
+            selected: field,
+        });
+    }
+
+    /// Start every process of a launch profile, stopping any session that is still running.
+    fn start_launch_profile(&mut self, project_index: usize, profile_index: usize) {
+        let Some(project) = self.config.projects.get(project_index) else {
+            return;
+        };
+        let Some(profile) = project.launch_profiles.get(profile_index).cloned() else {
+            return;
+        };
+        let project_path = project.path.clone();
+        let Some(engine_path) = self.config.unreal_engine_path.clone() else {
+            self.push_log("No engine path set.".into());
+            return;
+        };
+        self.stop_launch_session();
+
+        match crate::launch::start_session(
+            &engine_path,
+            &project_path,
+            &profile,
+            self.config.cancel_timeout(),
+        ) {
+            Ok(session) => {
+                self.flash_message = Some(format!(
+                    "Launched {} ({} processes)",
+                    profile.name,
+                    session.processes.len()
+                ));
+                self.launch_session = Some(session);
+                self.log_tab = 1;
+                self.follow_latest_logs();
+            }
+            Err(e) => {
+                self.push_log(format!("Failed to launch {}: {}", profile.name, e));
+                self.flash_message = Some(e);
+            }
+        }
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Kill every process of the running launch session.
+    pub fn stop_launch_session(&mut self) {
+        if let Some(session) = &self.launch_session {
+            if session.running_count() > 0 {
+                session.stop_all();
+                self.flash_message = Some(format!("Stopping {}", session.profile_name));
+                self.flash_until = self.tick + 60;
+            }
+        }
+    }
+
+    /// Stop the launch session before quitting and wait up to the cancel timeout for its
+    /// processes to exit. Whatever is still running then is force-killed.
+    pub async fn stop_launch_session_before_quit(&mut self) {
+        let timeout = self.config.cancel_timeout();
+        let Some(session) = self.launch_session.as_mut() else {
+            return;
+        };
+        session.stop_all();
+        if tokio::time::timeout(timeout, session.stopped())
+            .await
+            .is_err()
+        {
+            for pid in session.running_pids() {
+                crate::process::kill_tree(pid, &[]).await;
+            }
+        }
+    }
+
+    /// Drain the output of launched processes into their log tabs.
+    pub fn poll_launch_session(&mut self) {
+        let Some(session) = &mut self.launch_session else {
+            return;
+        };
+        for process in &mut session.processes {
+            while let Ok(line) = process.rx.try_recv() {
+                if let Some(line) = make_log_line(&line) {
+                    process.logs.push(line);
+                }
+            }
+            if process.logs.len() > 10_000 {
+                process.logs.drain(0..1000);
+            }
+        }
+    }
+
+    /// Cycle what the selected project launches after a successful build: off, editor, game.
+    pub fn cycle_auto_launch(&mut self) {
+        let Some(project) = self
+            .selected_project_index()
+            .and_then(|i| self.config.projects.get_mut(i))
+        else {
+            self.flash_message = Some("No project selected.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        project.auto_launch = match project.auto_launch {
+            None => Some(LaunchMode::Editor),
+            Some(LaunchMode::Editor) => Some(LaunchMode::Game),
+            Some(LaunchMode::Game) => None,
+        };
+        let label = project
+            .auto_launch
+            .map(|m| m.to_string())
+            .unwrap_or_else(|| "Off".to_string());
+        self.save_config();
+        self.flash_message = Some(format!("Auto-launch after build: {}", label));
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Launch the job's project if it asks for it, unless the job built no project binaries.
+    fn auto_launch_after(&mut self, job: &QueuedJob) {
+        if !job.settings.mode.builds_binaries() {
+            return;
+        }
+        let mode = self
+            .config
+            .projects
+            .iter()
+            .find(|p| p.path == job.project_path)
+            .and_then(|p| p.auto_launch);
+        if let Some(mode) = mode {
+            self.launch_project(&job.project_path, mode);
+        }
+    }
+
+    /// Mark the running queue job (if any) with its final status.
+    fn finish_running_job(&mut self, status: JobStatus) {
+        if let Some(job) = self
+            .queue
+            .iter_mut()
+            .find(|j| j.status == JobStatus::Running)
+        {
+            job.status = status;
+            let job = job.clone();
+            self.record_build(&job, status);
+        }
+    }
+
+    /// Append a finished job to the build history together with its log.
+    fn record_build(&mut self, job: &QueuedJob, status: JobStatus) {
+        let Some(started) = self.build_started.take() else {
+            return;
+        };
+        let result = match status {
+            JobStatus::Succeeded => BuildResult::Succeeded,
+            JobStatus::Cancelled => BuildResult::Cancelled,
+            _ => BuildResult::Failed,
+        };
+        let lines = &self.logs[self.build_log_start.min(self.logs.len())..];
+        let (errors, warnings) = self.job_problem_counts();
+        let engine_version = self.config.unreal_engine_path.as_deref().and_then(|path| {
+            engine::read_engine_version(path).or_else(|| {
+                self.engines
+                    .iter()
+                    .find(|e| e.path == path)
+                    .and_then(|e| e.version.clone())
+            })
+        });
+        let record = BuildRecord {
+            project_name: job.project_name.clone(),
+            project_path: job.project_path.clone(),
+            target: job.settings.target.clone(),
+            platform: job.settings.platform,
+            configuration: job.settings.configuration,
+            mode: job.settings.mode,
+            started_at: started.timestamp(),
+            duration_secs: (chrono::Local::now() - started).num_seconds().max(0) as u64,
+            result,
+            errors,
+            warnings,
+            engine_version,
+            log_file: None,
+        };
+        let dropped = self.build_log_dropped;
+        if let Err(e) = crate::history::record_build(&mut self.history, record, lines, dropped) {
+            self.push_log(format!("Could not save build history: {}", e));
+        }
+    }
+
+    /// Seconds since the running build started.
+    pub fn build_elapsed_secs(&self) -> Option<u64> {
+        self.build_started
+            .map(|started| (chrono::Local::now() - started).num_seconds().max(0) as u64)
+    }
+
+    /// Average duration of the last successful builds matching the running job.
+    fn previous_build_secs(&self) -> Option<u64> {
+        let job = self.queue.iter().find(|j| j.status == JobStatus::Running)?;
+        let durations: Vec<u64> = self
+            .history
+            .iter()
+            .rev()
+            .filter(|r| {
+                r.result == BuildResult::Succeeded
+                    && r.project_path == job.project_path
+                    && r.target == job.settings.target
+                    && r.platform == job.settings.platform
+                    && r.configuration == job.settings.configuration
+                    && r.mode == job.settings.mode
+            })
+            .take(3)
+            .map(|r| r.duration_secs)
+            .collect();
+        if durations.is_empty() {
+            return None;
+        }
+        Some(durations.iter().sum::<u64>() / durations.len() as u64)
+    }
+
+    /// Estimated seconds left for the running build. Extrapolates the UBT action rate,
+    /// leaning on previous builds of the same target while few actions have finished.
+    pub fn build_eta_secs(&self) -> Option<u64> {
+        let elapsed = self.build_elapsed_secs()?;
+        let from_history = self
+            .previous_build_secs()
+            .map(|previous| previous.saturating_sub(elapsed));
+        let from_rate = self
+            .build_progress
+            .filter(|(done, _)| *done > 0)
+            .map(|(done, total)| (elapsed * (total - done) as u64 / done as u64, done, total));
+        match (from_rate, from_history) {
+            (Some((rate, done, total)), Some(history)) => {
+                let weight = done as f64 / total as f64;
+                Some((rate as f64 * weight + history as f64 * (1.0 - weight)).round() as u64)
+            }
+            (Some((rate, _, _)), None) => Some(rate),
+            (None, history) => history,
+        }
+    }
+
+    /// Diagnostics parsed from the running (or just finished) job's part of the log.
+    fn job_diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        let start = self.build_log_start;
+        self.diagnostics
+            .iter()
+            .filter(move |d| d.log_index >= start)
+    }
+
+    /// Error and warning counts of the running (or just finished) job.
+    fn job_problem_counts(&self) -> (usize, usize) {
+        let errors = self
+            .job_diagnostics()
+            .filter(|d| d.severity == Severity::Error)
+            .count();
+        (errors, self.job_diagnostics().count() - errors)
+    }
+
+    fn summarize_build(
+        &self,
+        job: &QueuedJob,
+        outcome: &crate::build::BuildOutcome,
+    ) -> BuildSummary {
+        let success = outcome.success;
+        let (errors, warnings) = self.job_problem_counts();
+        let first_errors = self
+            .job_diagnostics()
+            .filter(|d| d.severity == Severity::Error)
+            .take(5)
+            .map(format_diagnostic)
+            .collect();
+        let mut slowest_actions = self.action_times.clone();
+        slowest_actions.sort_by(|a, b| b.1.total_cmp(&a.1));
+        slowest_actions.truncate(5);
+        let binaries = match (success, self.build_started) {
+            (true, Some(started)) if job.settings.mode.builds_binaries() => {
+                crate::build::find_new_binaries(&job.project_path, started.into())
+                    .into_iter()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .collect()
+            }
+            _ => Vec::new(),
+        };
+        BuildSummary {
+            job: job.clone(),
+            success,
+            duration_secs: outcome.duration.as_secs(),
+            exit_code: outcome.exit_code,
+            failure: outcome.failure.clone(),
+            errors,
+            warnings,
+            first_errors,
+            slowest_actions,
+            binaries,
+        }
+    }
+
+    /// Run the summarized job again.
+    pub fn rebuild_from_summary(&mut self) {
+        let Some(summary) = &self.last_summary else {
+            return;
+        };
+        let mut job = summary.job.clone();
+        job.status = JobStatus::Pending;
+        self.close_dialog();
+        self.queue_paused = false;
+        self.queue.push(job);
         if self.build_state != BuildState::Running {
+            self.start_next_job(true);
+        }
+    }
+
+    /// Copy every error of the build log to the clipboard.
+    pub fn copy_errors(&mut self) {
+        let text: Vec<String> = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .map(format_diagnostic)
+            .collect();
+        if text.is_empty() {
+            self.flash_message = Some("No errors to copy.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        match arboard::Clipboard::new().and_then(|mut cb| cb.set_text(text.join("\n"))) {
+            Ok(_) => {
+                self.flash_message = Some(format!("Copied {} errors to clipboard!", text.len()));
+            }
+            Err(e) => {
+                self.flash_message = Some(format!("Copy failed: {}", e));
+            }
+        }
+        self.flash_until = self.tick + 60;
+    }
+
+    /// Close the summary and select the first problem, focusing the problems panel.
+    pub fn jump_to_first_problem(&mut self) {
+        self.close_dialog();
+        if self.diagnostics.is_empty() {
+            self.flash_message = Some("No problems in the build log.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        self.problem_selected = None;
+        self.jump_to_problem(true);
+        self.focus = FocusItem::Problems;
+    }
+
+    pub fn open_history(&mut self) {
+        if self.history.is_empty() {
+            self.flash_message = Some("No builds recorded yet.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        self.dialog = Some(DialogKind::History { selected: 0 });
+    }
+
+    /// Replace the build log with the saved log of a history entry (0 is the newest).
+    fn open_history_log(&mut self, selected: usize) {
+        let Some(record) = self
+            .history
+            .len()
+            .checked_sub(selected + 1)
+            .and_then(|i| self.history.get(i))
+            .cloned()
+        else {
+            return;
+        };
+        if self.build_state == BuildState::Running {
+            self.flash_message = Some("Wait for the running build to finish first.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        }
+        let Some(log_file) = &record.log_file else {
+            self.flash_message = Some("No log was saved for this build.".into());
+            self.flash_until = self.tick + 60;
+            return;
+        };
+        match crate::history::load_log(log_file) {
+            Ok(lines) => {
+                self.clear_logs();
+                self.build_state = BuildState::Idle;
+                self.log_tab = 0;
+                self.push_log(format!(
+                    "──── {} {} ({} {}) started {} ────",
+                    record.mode,
+                    record.project_name,
+                    record.configuration,
+                    record.platform,
+                    format_timestamp(record.started_at)
+                ));
+                for line in lines {
+                    self.push_log(line);
+                }
+                self.push_log(format!(
+                    "──── {} after {} ────",
+                    record.result,
+                    format_duration(record.duration_secs)
+                ));
+            }
+            Err(e) => {
+                self.flash_message = Some(e);
+                self.flash_until = self.tick + 90;
+            }
+        }
+    }
+
+    pub fn pending_job_count(&self) -> usize {
+        self.queue
+            .iter()
+            .filter(|j| j.status == JobStatus::Pending)
+            .count()
+    }
+
+    pub fn open_build_queue(&mut self) {
+        self.dialog = Some(DialogKind::BuildQueue { selected: 0 });
+    }
+
+    /// Swap a pending job with its pending neighbour. Returns the job's new index.
+    pub fn move_queued_job(&mut self, index: usize, up: bool) -> usize {
+        let other = if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1).filter(|&i| i < self.queue.len())
+        };
+        let Some(other) = other else {
+            return index;
+        };
+        if self.queue[index].status != JobStatus::Pending
+            || self.queue[other].status != JobStatus::Pending
+        {
+            return index;
+        }
+        self.queue.swap(index, other);
+        other
+    }
+
+    /// Drop a pending job from the queue. Running and finished jobs are kept.
+    pub fn remove_queued_job(&mut self, index: usize) {
+        if self
+            .queue
+            .get(index)
+            .is_some_and(|j| j.status == JobStatus::Pending)
+        {
+            self.queue.remove(index);
+        }
+    }
+
+    /// Remove every finished (done, failed or cancelled) job from the queue.
+    pub fn clear_finished_jobs(&mut self) {
+        self.queue
+            .retain(|j| matches!(j.status, JobStatus::Pending | JobStatus::Running));
+    }
+
+    /// Ask the running build to stop. It stays running (and blocks the queue) until its
+    /// process tree has exited and `poll_build` sees the outcome.
+    pub fn cancel_build(&mut self) {
+        if self.build_state != BuildState::Running || self.build_cancelling {
             return;
         }
-        if let Some(handle) = self.build_handle.take() {
+        if let Some(handle) = &self.build_handle {
             handle.cancel();
         }
-        self.build_state = BuildState::Cancelled;
-        self.push_log("Build cancelled by user.".into());
+        self.build_cancelling = true;
+        self.queue_paused = true;
+        self.push_log("Cancelling build...".into());
+    }
+
+    /// Cancel the running build before quitting and wait up to the cancel timeout for its
+    /// process tree to stop. Whatever is still running then is force-killed.
+    pub async fn stop_build_before_quit(&mut self) {
+        self.cancel_build();
+        let Some(handle) = self.build_handle.as_mut() else {
+            return;
+        };
+        if !handle.is_finished() {
+            let timeout = self.config.cancel_timeout();
+            if tokio::time::timeout(timeout, handle.finished())
+                .await
+                .is_err()
+            {
+                if let Some(pid) = handle.process() {
+                    crate::process::kill_tree(pid, &[]).await;
+                }
+                self.finish_running_job(JobStatus::Cancelled);
+                self.build_handle = None;
+                return;
+            }
+        }
+        self.poll_build();
     }
 
-    /// Called every tick to drain log messages and check build completion.
+    /// Resolves once the running build has sent an event or finished, handling the first
+    /// event that arrived; `poll_build` then drains the rest. Never resolves while idle.
+    pub async fn wait_for_build(&mut self) {
+        enum Activity {
+            Event(Option<BuildEvent>),
+            Finished,
+        }
+        let log_rx = self.log_rx.as_mut();
+        let handle = self.build_handle.as_mut();
+        let activity = tokio::select! {
+            event = async {
+                match log_rx {
+                    Some(rx) => rx.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => Activity::Event(event),
+            _ = async {
+                match handle {
+                    Some(handle) => handle.finished().await,
+                    None => std::future::pending().await,
+                }
+            } => Activity::Finished,
+        };
+        match activity {
+            Activity::Event(Some(event)) => self.handle_build_event(event),
+            Activity::Event(None) => self.log_rx = None,
+            Activity::Finished => {}
+        }
+    }
+
+    fn handle_build_event(&mut self, event: BuildEvent) {
+        match event {
+            BuildEvent::Line {
+                phase,
+                stream,
+                text,
+            } => {
+                let before = self.logs.len();
+                self.push_log(text);
+                // Engine tools write problems to stderr without always tagging them. Hook
+                // scripts are left alone: tools like git report progress on stderr.
+                let hook = matches!(
+                    phase,
+                    BuildPhase::PreBuildHooks | BuildPhase::PostBuildHooks
+                );
+                if stream == OutputStream::Stderr && !hook && self.logs.len() > before {
+                    if let Some(line) = self.logs.last_mut() {
+                        if line.level == LogLevel::Info {
+                            line.level = LogLevel::Warning;
+                        }
+                    }
+                }
+            }
+            BuildEvent::PhaseStarted(phase) => self.build_phase = Some(phase),
+            BuildEvent::PhaseFinished {
+                phase,
+                success,
+                exit_code,
+            } => {
+                if success {
+                    return;
+                }
+                if let Some(code) = exit_code {
+                    self.push_log(format!("{} failed with exit code {}.", phase, code));
+                }
+                if phase == BuildPhase::PostBuildHooks {
+                    // The build itself already finished; only hooks went wrong.
+                    self.flash_message = Some("Post-build hook failed.".into());
+                    self.flash_until = self.tick + 60;
+                } else if self.failed_phase.is_none() {
+                    self.failed_phase = Some(phase);
+                }
+            }
+            BuildEvent::Progress {
+                done,
+                total,
+                action,
+            } => {
+                self.build_progress = Some((done, total));
+                if let Some(action) = action {
+                    self.action_times.push(action);
+                }
+            }
+        }
+    }
+
+    /// Drain log messages and check build completion.
     pub fn poll_build(&mut self) {
-        let mut lines = Vec::new();
+        // Take the outcome before draining: the job's last lines are sent before it, so
+        // they are all handled before the job is finished and recorded.
+        let finished = if self.build_state == BuildState::Running {
+            self.build_handle.as_mut().and_then(|h| h.try_finished())
+        } else {
+            None
+        };
+        let mut events = Vec::new();
         let mut disconnected = false;
         if let Some(rx) = &mut self.log_rx {
             loop {
                 match rx.try_recv() {
-                    Ok(line) => lines.push(line),
+                    Ok(event) => events.push(event),
                     Err(mpsc::error::TryRecvError::Empty) => break,
                     Err(mpsc::error::TryRecvError::Disconnected) => {
                         disconnected = true;
@@ -446,43 +2370,106 @@ impl App {
                 }
             }
         }
-        for line in lines {
-            self.push_log(line);
+        for event in events {
+            self.handle_build_event(event);
         }
         if disconnected {
             self.log_rx = None;
         }
 
-        if self.build_state == BuildState::Running {
-            let finished = self.build_handle.as_ref().and_then(|h| h.try_finished());
-            if let Some(success) = finished {
-                self.build_state = if success {
-                    BuildState::Success
-                } else {
-                    BuildState::Error
-                };
-                if success {
-                    self.push_log("Build completed successfully.".into());
-                    crate::notify::on_build_success();
-                } else {
-                    self.push_log("Build finished with errors.".into());
-                    crate::notify::on_build_failed();
-                    if self
-                        .logs
-                        .iter()
-                        .rev()
-                        .take(200)
-                        .any(|l| crate::build::looks_like_target_error(&l.text))
-                    {
-                        let _ = self.prompt_editor_target_resolution(
-                            "Build failed with a target-related error. Choose the correct editor target and rebuild.",
-                        );
-                    }
-                }
-                self.follow_latest_logs();
-                self.build_handle = None;
+        if let Some(outcome) = finished {
+            if self.build_cancelling {
+                self.finish_cancelled_build(&outcome);
+            } else {
+                self.finish_build(outcome);
+            }
+        }
+
+        if self.build_state != BuildState::Running
+            && !self.queue_paused
+            && self.restore_rx.is_none()
+        {
+            self.start_next_job(false);
+        }
+    }
+
+    /// Report a finished build, record it and show its summary.
+    fn finish_build(&mut self, outcome: crate::build::BuildOutcome) {
+        let success = outcome.success;
+        self.build_state = if success {
+            BuildState::Success
+        } else {
+            BuildState::Error
+        };
+        let duration = format_duration(outcome.duration.as_secs());
+        if success {
+            self.push_log(format!("Build completed successfully in {}.", duration));
+            crate::notify::on_build_success();
+        } else {
+            let reason = outcome.failure.as_deref().unwrap_or("failed");
+            self.push_log(match self.failed_phase {
+                Some(phase) => format!(
+                    "Build finished with errors after {} ({}: {}).",
+                    duration, phase, reason
+                ),
+                None => format!(
+                    "Build finished with errors after {} ({}).",
+                    duration, reason
+                ),
+            });
+            crate::notify::on_build_failed();
+            if self
+                .logs
+                .iter()
+                .rev()
+                .take(200)
+                .any(|l| crate::build::looks_like_target_error(&l.text))
+            {
+                let _ = self.prompt_editor_target_resolution(
+                    "Build failed with a target-related error. Choose the correct editor target and rebuild.",
+                );
+            }
+        }
+        let finished_job = self
+            .queue
+            .iter()
+            .find(|j| j.status == JobStatus::Running)
+            .cloned();
+        if let Some(job) = &finished_job {
+            self.last_summary = Some(self.summarize_build(job, &outcome));
+        }
+        self.finish_running_job(if success {
+            JobStatus::Succeeded
+        } else {
+            JobStatus::Failed
+        });
+        // Show the summary once the queue is drained, unless another prompt is open.
+        if self.pending_job_count() == 0 && self.dialog.is_none() {
+            self.dialog = Some(DialogKind::BuildSummary);
+        }
+        if success && self.pending_job_count() == 0 {
+            if let Some(job) = finished_job {
+                self.auto_launch_after(&job);
             }
         }
+        self.follow_latest_logs();
+        self.build_handle = None;
+    }
+
+    /// Record a cancelled build once its process tree has stopped.
+    fn finish_cancelled_build(&mut self, outcome: &crate::build::BuildOutcome) {
+        self.build_cancelling = false;
+        self.build_state = BuildState::Cancelled;
+        self.finish_running_job(JobStatus::Cancelled);
+        self.push_log(format!(
+            "Build cancelled by user after {}.",
+            format_duration(outcome.duration.as_secs())
+        ));
+        if self.pending_job_count() > 0 {
+            self.push_log("Build queue paused. Start another build to resume it.".into());
+        }
+        self.follow_latest_logs();
+        self.build_handle = None;
     }
 
     fn set_editor_target(&mut self, project_index: usize, editor_target: String) -> bool {
@@ -493,6 +2480,7 @@ impl App {
         if let Some(project) = self.config.projects.get_mut(project_index) {
             let project_name = project.name.clone();
             project.editor_target = Some(trimmed.clone());
+            project.target = None;
             self.save_config();
             self.flash_message = Some(format!(
                 "Editor target for {} set to {}",
@@ -504,6 +2492,54 @@ impl App {
         false
     }
 
+    /// Set (or with None, reset to auto-detect) the build target of a project.
+    fn set_build_target(&mut self, project_index: usize, target: Option<String>) {
+        let target = target
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty());
+        if let Some(project) = self.config.projects.get_mut(project_index) {
+            let message = match &target {
+                Some(t) => format!("Build target for {} set to {}", project.name, t),
+                None => format!("Build target for {} set to auto-detect", project.name),
+            };
+            project.target = target;
+            self.save_config();
+            self.flash_message = Some(message);
+            self.flash_until = self.tick + 90;
+        }
+    }
+
+    /// Open the picker listing every target found in the selected project's Source/ folder.
+    pub fn open_target_picker(&mut self) {
+        let Some(project_index) = self.selected_project_index() else {
+            self.push_log("No project selected. Select one in Projects and press Enter.".into());
+            return;
+        };
+        let Some(project) = self.config.projects.get(project_index) else {
+            return;
+        };
+
+        let targets = match crate::build::discover_targets(&project.path) {
+            Ok(v) => v,
+            Err(e) => {
+                self.push_log(format!("Failed to detect build targets: {}", e));
+                return;
+            }
+        };
+
+        let selected = project
+            .target
+            .as_ref()
+            .and_then(|name| targets.iter().position(|t| &t.name == name))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.dialog = Some(DialogKind::TargetPicker {
+            project_index,
+            targets,
+            selected,
+        });
+    }
+
     fn prompt_editor_target_resolution(&mut self, reason: &str) -> bool {
         let Some(project_index) = self.selected_project_index() else {
             return false;
@@ -596,6 +2632,88 @@ impl App {
             None => return,
         };
         match dialog {
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::SetCookFlavor(project_index),
+                ..
+            } => {
+                let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+                self.edit_package_settings(project_index, |p| p.cook_flavor = value);
+                self.dialog = Some(DialogKind::PackageOptions {
+                    project_index,
+                    selected: 3,
+                });
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::SetPackageOutputDir(project_index),
+                ..
+            } => {
+                let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+                self.edit_package_settings(project_index, |p| p.output_dir = value);
+                self.dialog = Some(DialogKind::PackageOptions {
+                    project_index,
+                    selected: 4,
+                });
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::PresetField(project_index, preset_index, field),
+                ..
+            } => {
+                self.set_preset_field(project_index, preset_index, field, value.trim().to_string());
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::LaunchProfileField(project_index, profile_index, field),
+                ..
+            } => {
+                self.set_launch_profile_field(
+                    project_index,
+                    profile_index,
+                    field,
+                    value.trim().to_string(),
+                );
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::AddHook(project_index, stage),
+                ..
+            } => self.save_hook(project_index, stage, None, value.trim().to_string()),
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::EditHook(project_index, stage, index),
+                ..
+            } => self.save_hook(project_index, stage, Some(index), value.trim().to_string()),
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::SetEditorCommand,
+                ..
+            } => {
+                self.config.editor_command =
+                    Some(value.trim().to_string()).filter(|v| !v.is_empty());
+                self.save_config();
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::SetExtraArgs(project_index),
+                ..
+            } => {
+                if let Some(project) = self.config.projects.get_mut(project_index) {
+                    project.extra_args = value.split_whitespace().map(String::from).collect();
+                    self.save_config();
+                }
+            }
+            DialogKind::PathInput {
+                value,
+                target: PathInputTarget::SetEnv(project_index),
+                ..
+            } => {
+                if let Some(project) = self.config.projects.get_mut(project_index) {
+                    project.env = parse_env(&value);
+                    self.save_config();
+                }
+            }
             DialogKind::PathInput { value, target, .. } => {
                 let trimmed = value.trim().to_string();
                 if !trimmed.is_empty() {
@@ -605,6 +2723,24 @@ impl App {
                         PathInputTarget::SetEditorTarget(project_index) => {
                             let _ = self.set_editor_target(project_index, trimmed);
                         }
+                        PathInputTarget::SetBuildTarget(project_index) => {
+                            self.set_build_target(project_index, Some(trimmed));
+                        }
+                        PathInputTarget::NewPreset(project_index) => {
+                            self.create_preset(project_index, trimmed);
+                        }
+                        PathInputTarget::NewLaunchProfile(project_index) => {
+                            self.create_launch_profile(project_index, trimmed);
+                        }
+                        PathInputTarget::SetCookFlavor(_)
+                        | PathInputTarget::SetPackageOutputDir(_)
+                        | PathInputTarget::SetExtraArgs(_)
+                        | PathInputTarget::SetEnv(_)
+                        | PathInputTarget::SetEditorCommand
+                        | PathInputTarget::AddHook(..)
+                        | PathInputTarget::EditHook(..)
+                        | PathInputTarget::PresetField(..)
+                        | PathInputTarget::LaunchProfileField(..) => {}
                     }
                 }
             }
@@ -620,14 +2756,144 @@ impl App {
                     let _ = self.set_editor_target(project_index, choice.clone());
                 }
             }
+            DialogKind::TargetPicker {
+                project_index,
+                targets,
+                selected,
+            } => {
+                let choice = selected
+                    .checked_sub(1)
+                    .and_then(|i| targets.get(i))
+                    .map(|t| t.name.clone());
+                self.set_build_target(project_index, choice);
+            }
+            DialogKind::Presets {
+                project_index,
+                selected,
+            } => self.activate_preset(project_index, selected),
+            DialogKind::LaunchProfiles {
+                project_index,
+                selected,
+            } => self.start_launch_profile(project_index, selected),
+            DialogKind::History { selected } => self.open_history_log(selected),
+            DialogKind::BuildSummary => self.jump_to_first_problem(),
+            DialogKind::ProjectFilesPicker {
+                project_index,
+                selected,
+            } => self.pick_project_file_format(project_index, selected),
+            DialogKind::PackageOptions { .. }
+            | DialogKind::PresetEditor { .. }
+            | DialogKind::LaunchProfileEditor { .. }
+            | DialogKind::Hooks { .. }
+            | DialogKind::Clean { .. }
+            | DialogKind::BuildQueue { .. } => {}
             DialogKind::Confirm { action, .. } => match action {
                 ConfirmAction::RemoveProject(idx) => self.remove_project(idx),
+                ConfirmAction::DeletePreset(project_index, preset_index) => {
+                    self.delete_preset(project_index, preset_index)
+                }
+                ConfirmAction::DeleteLaunchProfile(project_index, profile_index) => {
+                    self.delete_launch_profile(project_index, profile_index)
+                }
             },
             DialogKind::Help => {}
         }
     }
 }
 
+fn hook_input_label(stage: HookStage) -> &'static str {
+    match stage {
+        HookStage::Pre => "Pre-build Hook ({project_dir} {engine} {target} ...)",
+        HookStage::Post => "Post-build Hook ({project_dir} {engine} {target} {result} ...)",
+    }
+}
+
+/// One-line form of a diagnostic, e.g. "Foo.cpp(12,5): error C2065: message".
+pub fn format_diagnostic(diagnostic: &Diagnostic) -> String {
+    let severity = match diagnostic.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    };
+    let code = diagnostic
+        .code
+        .as_ref()
+        .map(|c| format!(" {}", c))
+        .unwrap_or_default();
+    let location = match (&diagnostic.file, diagnostic.line, diagnostic.column) {
+        (Some(file), Some(line), Some(column)) => format!("{}({},{}): ", file, line, column),
+        (Some(file), Some(line), None) => format!("{}({}): ", file, line),
+        (Some(file), None, _) => format!("{}: ", file),
+        (None, _, _) => String::new(),
+    };
+    format!("{}{}{}: {}", location, severity, code, diagnostic.message)
+}
+
+/// Format Unix seconds as local time, e.g. "2024-05-01 14:03".
+pub fn format_timestamp(secs: i64) -> String {
+    use chrono::TimeZone;
+    chrono::Local
+        .timestamp_opt(secs, 0)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+/// Human-readable byte count, e.g. "1.4 GB".
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Format a duration as "42s", "3m 05s" or "1h 02m".
+pub fn format_duration(secs: u64) -> String {
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+    }
+}
+
+/// Render environment variables as `KEY=VALUE; KEY2=VALUE2` for editing.
+pub fn format_env(env: &std::collections::BTreeMap<String, String>) -> String {
+    env.iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Parse `KEY=VALUE; KEY2=VALUE2`, skipping entries without a key.
+fn parse_env(input: &str) -> std::collections::BTreeMap<String, String> {
+    input
+        .split(';')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            let key = key.trim();
+            (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Sanitize and classify one line of process output. Returns None for blank lines.
+fn make_log_line(text: &str) -> Option<LogLine> {
+    let text = sanitize_log_text(text);
+    if text.is_empty() {
+        return None;
+    }
+    let level = classify_log_line(&text);
+    Some(LogLine { text, level })
+}
+
 fn classify_log_line(line: &str) -> LogLevel {
     let lower = line.to_lowercase();
     if lower.contains("error") || lower.contains("fatal") {
@@ -649,7 +2915,7 @@ fn sanitize_log_text(input: &str) -> String {
         if ch == '\u{1b}' {
             if matches!(chars.peek(), Some('[')) {
                 let _ = chars.next();
-                while let Some(next) = chars.next() {
+                for next in chars.by_ref() {
                     if ('@'..='~').contains(&next) {
                         break;
                     }
@@ -0,0 +1,618 @@
+use crate::build::{expand_placeholders, BuildJob};
+use crate::types::{BuildMode, BuildPhase, BuildSettings};
+use std::path::{Path, PathBuf};
+
+/// A program and its arguments, as built by a backend.
+#[derive(Debug, Clone)]
+pub struct CommandLine {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Environment the toolchain needs, applied before the job's own variables.
+    pub env: Vec<(String, String)>,
+    /// Pass the arguments to the program verbatim instead of quoting each one. `cmd`
+    /// does not understand the escaped quotes of the usual Windows quoting.
+    pub raw: bool,
+}
+
+impl CommandLine {
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            env: Vec::new(),
+            raw: false,
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args(mut self, args: impl IntoIterator<Item = String>) -> Self {
+        self.args.extend(args);
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// The command line as shown in the log, quoting parts that contain spaces.
+    pub fn display(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|part| {
+                if part.contains(char::is_whitespace) {
+                    format!("\"{}\"", part)
+                } else {
+                    part.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    pub fn to_command(&self) -> tokio::process::Command {
+        let mut cmd = tokio::process::Command::new(&self.program);
+        #[cfg(windows)]
+        if self.raw {
+            for arg in &self.args {
+                cmd.raw_arg(arg);
+            }
+        } else {
+            cmd.args(&self.args);
+        }
+        #[cfg(not(windows))]
+        cmd.args(&self.args);
+        cmd.envs(self.env.iter().cloned());
+        cmd
+    }
+}
+
+/// Runs the main step of a job. Every backend gets the same hooks, output streaming,
+/// cancellation and notifications from the build runner.
+pub trait BuildBackend: Send + Sync {
+    /// Tool name for the log, e.g. "UnrealBuildTool".
+    fn name(&self) -> &str;
+
+    /// Phase the step reports as.
+    fn phase(&self) -> BuildPhase;
+
+    /// Which toolchain runs the step, for the log.
+    fn toolchain(&self) -> Option<String> {
+        None
+    }
+
+    fn command(&self, job: &BuildJob) -> CommandLine;
+}
+
+/// What UnrealBuildTool is asked to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UbtTask {
+    /// Compile the job's target.
+    Compile,
+    /// Generate IDE project files in the job's chosen format.
+    ProjectFiles,
+}
+
+impl UbtTask {
+    fn phase(self) -> BuildPhase {
+        match self {
+            UbtTask::Compile => BuildPhase::Compile,
+            UbtTask::ProjectFiles => BuildPhase::GenerateProjectFiles,
+        }
+    }
+
+    /// Arguments passed to UnrealBuildTool for this task.
+    fn args(self, job: &BuildJob) -> Vec<String> {
+        let mut args = match self {
+            UbtTask::Compile => vec![
+                job.target_name.clone(),
+                job.settings.platform.ubt_name().to_string(),
+                job.settings.configuration.ubt_name().to_string(),
+                format!("-Project={}", job.project_path),
+                "-WaitMutex".to_string(),
+            ],
+            UbtTask::ProjectFiles => {
+                let mut args = vec![
+                    "-ProjectFiles".to_string(),
+                    format!("-Project={}", job.project_path),
+                    "-Game".to_string(),
+                    "-Engine".to_string(),
+                ];
+                if let Some(format) = job.settings.project_file_format {
+                    args.push(format!("-ProjectFileFormat={}", format.ubt_name()));
+                }
+                args
+            }
+        };
+        args.extend(job.settings.extra_args.iter().cloned());
+        args
+    }
+}
+
+/// UnrealBuildTool's assembly, run through a `dotnet` host.
+pub struct UnrealBuildTool {
+    dotnet: PathBuf,
+    /// Whether `dotnet` is the runtime shipped with the engine rather than one on PATH.
+    bundled: bool,
+    dll: PathBuf,
+    task: UbtTask,
+}
+
+impl BuildBackend for UnrealBuildTool {
+    fn name(&self) -> &str {
+        "UnrealBuildTool"
+    }
+
+    fn phase(&self) -> BuildPhase {
+        self.task.phase()
+    }
+
+    fn toolchain(&self) -> Option<String> {
+        Some(if self.bundled {
+            format!("engine's bundled .NET at {}", self.dotnet.display())
+        } else {
+            format!("dotnet from PATH at {}", self.dotnet.display())
+        })
+    }
+
+    fn command(&self, job: &BuildJob) -> CommandLine {
+        let mut command = CommandLine::new(self.dotnet.to_string_lossy())
+            .arg(self.dll.to_string_lossy())
+            .args(self.task.args(job));
+        if self.bundled {
+            // Same as the engine's own scripts: keep UBT and the tools it starts on the
+            // bundled runtime instead of any global install.
+            if let Some(root) = self.dotnet.parent() {
+                let mut paths = vec![root.to_path_buf()];
+                paths.extend(std::env::split_paths(
+                    &std::env::var_os("PATH").unwrap_or_default(),
+                ));
+                if let Ok(path) = std::env::join_paths(paths) {
+                    command = command.env("PATH", path.to_string_lossy());
+                }
+                command = command
+                    .env("DOTNET_ROOT", root.to_string_lossy())
+                    .env("DOTNET_MULTILEVEL_LOOKUP", "0");
+            }
+        }
+        command
+    }
+}
+
+/// The engine's `Build.sh` / `Build.bat`, which builds UnrealBuildTool first when needed
+/// and forwards its arguments to it.
+pub struct BuildScript {
+    script: PathBuf,
+    task: UbtTask,
+}
+
+impl BuildBackend for BuildScript {
+    fn name(&self) -> &str {
+        "Build script"
+    }
+
+    fn phase(&self) -> BuildPhase {
+        self.task.phase()
+    }
+
+    fn toolchain(&self) -> Option<String> {
+        Some(format!(
+            "{}, which sets up the engine's .NET itself",
+            self.script.display()
+        ))
+    }
+
+    fn command(&self, job: &BuildJob) -> CommandLine {
+        script_command(&self.script).args(self.task.args(job))
+    }
+}
+
+/// `RunUAT BuildCookRun`, which builds, cooks and packages the project.
+pub struct RunUat {
+    script: PathBuf,
+}
+
+impl BuildBackend for RunUat {
+    fn name(&self) -> &str {
+        "RunUAT"
+    }
+
+    fn phase(&self) -> BuildPhase {
+        BuildPhase::Package
+    }
+
+    fn command(&self, job: &BuildJob) -> CommandLine {
+        script_command(&self.script).args(build_cook_run_args(job))
+    }
+}
+
+/// A shell command from a preset (a commandlet, an automation test run, a custom cook),
+/// with the hook placeholders expanded.
+pub struct UserCommand {
+    command: String,
+}
+
+impl BuildBackend for UserCommand {
+    fn name(&self) -> &str {
+        "command"
+    }
+
+    fn phase(&self) -> BuildPhase {
+        BuildPhase::Command
+    }
+
+    fn command(&self, job: &BuildJob) -> CommandLine {
+        shell_command(&expand_placeholders(job, &self.command, None))
+    }
+}
+
+/// Run `command` through the platform shell (`sh -c`, or `cmd /C` on Windows).
+pub fn shell_command(command: &str) -> CommandLine {
+    if cfg!(windows) {
+        let mut cmd = CommandLine::new("cmd").arg("/C").arg(command);
+        cmd.raw = true;
+        cmd
+    } else {
+        CommandLine::new("sh").arg("-c").arg(command)
+    }
+}
+
+/// Quote `value` as a single word for the shell `shell_command` runs, so paths with
+/// spaces or shell characters are passed through literally. Plain values stay bare.
+pub fn shell_quote(value: &str) -> String {
+    // `\` separates Windows paths but escapes in sh.
+    let safe = if cfg!(windows) {
+        "-_./\\:+@"
+    } else {
+        "-_./:+@"
+    };
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || safe.contains(c));
+    if plain {
+        value.to_string()
+    } else if cfg!(windows) {
+        // Windows paths cannot contain `"`, so wrapping them is enough for cmd.
+        format!("\"{}\"", value)
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// Batch files run directly on Windows and shell scripts through bash elsewhere.
+fn script_command(script: &Path) -> CommandLine {
+    let script = script.to_string_lossy().to_string();
+    if cfg!(windows) {
+        CommandLine::new(script)
+    } else {
+        CommandLine::new("bash").arg(script)
+    }
+}
+
+/// The backend for the main step of a job with `settings`: the preset's command if it
+/// has one, RunUAT for packaging and UnrealBuildTool otherwise.
+pub fn for_settings(
+    engine_path: &str,
+    settings: &BuildSettings,
+) -> Result<Box<dyn BuildBackend>, String> {
+    if let Some(command) = &settings.command {
+        return Ok(Box::new(UserCommand {
+            command: command.clone(),
+        }));
+    }
+    match settings.mode {
+        BuildMode::Package => {
+            let script = run_uat_script(engine_path);
+            if !script.exists() {
+                return Err(format!("RunUAT not found at {}", script.display()));
+            }
+            Ok(Box::new(RunUat { script }))
+        }
+        BuildMode::GenerateProjectFiles => unreal_build_tool(engine_path, UbtTask::ProjectFiles),
+        BuildMode::Standard | BuildMode::CleanRebuild => {
+            unreal_build_tool(engine_path, UbtTask::Compile)
+        }
+    }
+}
+
+/// The backend running UnrealBuildTool for `engine_path`, resolving .NET the way the
+/// engine does: its assembly on the engine's bundled runtime, else the engine's Build
+/// script, and only then on a `dotnet` from PATH.
+pub fn unreal_build_tool(
+    engine_path: &str,
+    task: UbtTask,
+) -> Result<Box<dyn BuildBackend>, String> {
+    let dll = PathBuf::from(engine_path)
+        .join("Engine/Binaries/DotNET/UnrealBuildTool/UnrealBuildTool.dll");
+    if dll.exists() {
+        if let Some(dotnet) = bundled_dotnet(engine_path) {
+            return Ok(Box::new(UnrealBuildTool {
+                dotnet,
+                bundled: true,
+                dll,
+                task,
+            }));
+        }
+    }
+    let script = build_script(engine_path);
+    if script.exists() {
+        return Ok(Box::new(BuildScript { script, task }));
+    }
+    if !dll.exists() {
+        return Err(format!(
+            "UnrealBuildTool not found at {} (and no {})",
+            dll.display(),
+            script.display()
+        ));
+    }
+    match dotnet_on_path() {
+        Some(dotnet) => Ok(Box::new(UnrealBuildTool {
+            dotnet,
+            bundled: false,
+            dll,
+            task,
+        })),
+        None => Err(format!(
+            "No .NET runtime for UnrealBuildTool: none bundled under {} and no dotnet on PATH",
+            dotnet_root(engine_path).display()
+        )),
+    }
+}
+
+fn dotnet_root(engine_path: &str) -> PathBuf {
+    PathBuf::from(engine_path).join("Engine/Binaries/ThirdParty/DotNet")
+}
+
+fn dotnet_executable() -> &'static str {
+    if cfg!(windows) {
+        "dotnet.exe"
+    } else {
+        "dotnet"
+    }
+}
+
+/// Folder names the engine uses for the host's runtime, across engine versions.
+fn dotnet_platform_dirs() -> &'static [&'static str] {
+    if cfg!(windows) {
+        &["win-x64", "windows", "Windows"]
+    } else if cfg!(target_os = "macos") {
+        if cfg!(target_arch = "aarch64") {
+            &["mac-arm64", "osx-arm64", "mac-x64", "osx-x64", "Mac"]
+        } else {
+            &["mac-x64", "osx-x64", "Mac"]
+        }
+    } else if cfg!(target_arch = "aarch64") {
+        &["linux-arm64", "linux", "Linux"]
+    } else {
+        &["linux-x64", "linux", "Linux"]
+    }
+}
+
+/// The runtime the engine ships for UnrealBuildTool: `DotNet/<version>/<platform>/dotnet`
+/// with the newest version first, or `DotNet/<platform>/dotnet` on engines that do not
+/// version the folder.
+fn bundled_dotnet(engine_path: &str) -> Option<PathBuf> {
+    let root = dotnet_root(engine_path);
+    let mut versions: Vec<(Vec<u32>, PathBuf)> = std::fs::read_dir(&root)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let version = name
+                .split('.')
+                .map(|part| part.parse().ok())
+                .collect::<Option<Vec<u32>>>()?;
+            Some((version, entry.path()))
+        })
+        .collect();
+    versions.sort_by(|a, b| b.0.cmp(&a.0));
+
+    versions
+        .into_iter()
+        .map(|(_, dir)| dir)
+        .chain(std::iter::once(root))
+        .flat_map(|dir| {
+            dotnet_platform_dirs()
+                .iter()
+                .map(move |platform| dir.join(platform).join(dotnet_executable()))
+        })
+        .find(|path| path.is_file())
+}
+
+fn dotnet_on_path() -> Option<PathBuf> {
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(dotnet_executable()))
+        .find(|path| path.is_file())
+}
+
+/// Path to the engine's RunUAT batch file for the host OS.
+fn run_uat_script(engine_path: &str) -> PathBuf {
+    let script = if cfg!(windows) {
+        "RunUAT.bat"
+    } else {
+        "RunUAT.sh"
+    };
+    PathBuf::from(engine_path)
+        .join("Engine/Build/BatchFiles")
+        .join(script)
+}
+
+/// Path to the engine's Build batch file for the host OS.
+fn build_script(engine_path: &str) -> PathBuf {
+    let script = if cfg!(windows) {
+        "Build.bat"
+    } else if cfg!(target_os = "macos") {
+        "Mac/Build.sh"
+    } else {
+        "Linux/Build.sh"
+    };
+    PathBuf::from(engine_path)
+        .join("Engine/Build/BatchFiles")
+        .join(script)
+}
+
+/// Arguments for `RunUAT BuildCookRun` derived from the job's package settings.
+fn build_cook_run_args(job: &BuildJob) -> Vec<String> {
+    let package = &job.settings.package;
+    let mut args = vec![
+        "BuildCookRun".to_string(),
+        format!("-project={}", job.project_path),
+        "-noP4".to_string(),
+        format!("-platform={}", job.settings.platform.ubt_name()),
+        format!("-clientconfig={}", job.settings.configuration.ubt_name()),
+        "-build".to_string(),
+        "-cook".to_string(),
+        "-utf8output".to_string(),
+    ];
+    if !job.target_name.is_empty() {
+        args.push(format!("-target={}", job.target_name));
+    }
+    if let Some(flavor) = package.cook_flavor.as_deref().filter(|f| !f.is_empty()) {
+        args.push(format!("-cookflavor={}", flavor));
+    }
+    if package.pak {
+        args.push("-pak".to_string());
+    }
+    // The output directory is where the build ends up: the archive if there is one,
+    // otherwise the staged build.
+    let output_dir = package.output_dir.as_deref().filter(|d| !d.is_empty());
+    if package.stage {
+        args.push("-stage".to_string());
+        if let (Some(dir), false) = (output_dir, package.archive) {
+            args.push(format!("-stagingdirectory={}", dir));
+        }
+    }
+    if package.archive {
+        args.push("-archive".to_string());
+        if let Some(dir) = output_dir {
+            args.push(format!("-archivedirectory={}", dir));
+        }
+    }
+    if !job.settings.extra_args.is_empty() {
+        args.push(format!("-ubtargs={}", job.settings.extra_args.join(" ")));
+    }
+    args.extend(job.settings.package_args.iter().cloned());
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PackageSettings, ProjectConfig};
+
+    /// A folder under the temp dir, removed again when dropped (also if a test fails).
+    struct ScratchDir(PathBuf);
+
+    impl ScratchDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("stellar-{}-{}", name, std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            Self(dir)
+        }
+    }
+
+    impl Drop for ScratchDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn package_job(target_name: &str, package: PackageSettings) -> BuildJob {
+        let mut project: ProjectConfig =
+            serde_json::from_str(r#"{"name": "Game", "path": "/p/Game.uproject"}"#).unwrap();
+        project.package = package;
+        project.extra_args = vec![
+            "-MaxParallelActions=4".to_string(),
+            "-nodebuginfo".to_string(),
+        ];
+        BuildJob::for_test(
+            &project.path,
+            target_name,
+            project.build_settings(Some(BuildMode::Package)),
+            Box::new(UserCommand {
+                command: String::new(),
+            }),
+        )
+    }
+
+    #[test]
+    fn build_cook_run_args_follow_package_settings() {
+        let args = build_cook_run_args(&package_job("", PackageSettings::default()));
+        assert_eq!(args[0], "BuildCookRun");
+        assert!(args.contains(&"-project=/p/Game.uproject".to_string()));
+        assert!(!args.iter().any(|a| a.starts_with("-target=")));
+        for flag in ["-build", "-cook", "-pak", "-stage", "-archive"] {
+            assert!(args.contains(&flag.to_string()), "missing {}", flag);
+        }
+        assert_eq!(
+            args.last().map(String::as_str),
+            Some("-ubtargs=-MaxParallelActions=4 -nodebuginfo")
+        );
+        assert!(!args.contains(&"-nodebuginfo".to_string()));
+
+        let args = build_cook_run_args(&package_job(
+            "GameClient",
+            PackageSettings {
+                cook_flavor: Some("ASTC".to_string()),
+                output_dir: Some("/out".to_string()),
+                pak: false,
+                stage: false,
+                archive: true,
+            },
+        ));
+        assert!(args.contains(&"-target=GameClient".to_string()));
+        assert!(args.contains(&"-cookflavor=ASTC".to_string()));
+        assert!(args.contains(&"-archivedirectory=/out".to_string()));
+        assert!(!args.contains(&"-pak".to_string()));
+        assert!(!args.contains(&"-stage".to_string()));
+
+        // Without an archive the output directory receives the staged build.
+        let args = build_cook_run_args(&package_job(
+            "",
+            PackageSettings {
+                output_dir: Some("/out".to_string()),
+                archive: false,
+                ..PackageSettings::default()
+            },
+        ));
+        assert!(args.contains(&"-stagingdirectory=/out".to_string()));
+        assert!(!args.iter().any(|a| a.starts_with("-archivedirectory=")));
+    }
+
+    #[test]
+    fn shell_quote_keeps_values_literal() {
+        assert_eq!(shell_quote("/p/Game.uproject"), "/p/Game.uproject");
+        if cfg!(windows) {
+            assert_eq!(shell_quote(r"C:\My Games\Game"), r#""C:\My Games\Game""#);
+        } else {
+            assert_eq!(shell_quote("/My Games/Game"), "'/My Games/Game'");
+            assert_eq!(shell_quote("/p/it's; rm -rf ~"), r"'/p/it'\''s; rm -rf ~'");
+            assert_eq!(shell_quote(""), "''");
+        }
+    }
+
+    #[test]
+    fn bundled_dotnet_prefers_the_newest_version() {
+        let scratch = ScratchDir::new("dotnet");
+        let engine = scratch.0.to_string_lossy();
+        let root = dotnet_root(&engine);
+        let platform = dotnet_platform_dirs()[0];
+        // "10.0.0" sorts before "8.0.1" as text; versions must compare numerically.
+        for version in ["6.0.302", "8.0.1", "10.0.0", "notes"] {
+            let dir = root.join(version).join(platform);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(dotnet_executable()), "").unwrap();
+        }
+
+        let found = bundled_dotnet(&engine);
+        assert_eq!(
+            found,
+            Some(root.join("10.0.0").join(platform).join(dotnet_executable()))
+        );
+    }
+}
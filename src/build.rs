@@ -1,39 +1,280 @@
+use crate::backend::{BuildBackend, UbtTask};
+use crate::types::{
+    BuildMode, BuildPhase, BuildSettings, BuildTarget, OutputStream, TargetKind, PLUGIN_BINARIES,
+    PLUGIN_INTERMEDIATE,
+};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot, Notify};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BuildMode {
-    Standard,
-    CleanRebuild,
+/// Everything needed to run one build once the target has been resolved.
+pub struct BuildJob {
+    pub engine_path: String,
+    /// Empty for packaging jobs that let BuildCookRun choose the target.
+    pub target_name: String,
+    pub settings: BuildSettings,
+    pub project_path: String,
+    pub project_dir: Option<PathBuf>,
+    /// Runs the job's main step.
+    backend: Box<dyn BuildBackend>,
+    /// Regenerates project files before a clean rebuild compiles.
+    project_files: Option<Box<dyn BuildBackend>>,
+    /// Days clean backups are kept before being pruned.
+    backup_max_age_days: u32,
+    /// How long a cancelled process tree gets to exit before it is force-killed.
+    cancel_timeout: Duration,
+}
+
+/// Cancellation signal shared between the UI and the tasks of a running job. Clones
+/// share the same state.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    state: Arc<CancelState>,
+}
+
+#[derive(Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    notify: Notify,
+    /// Pid of the process `run_streamed` is running for the job, 0 when none.
+    process: AtomicU32,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signal cancellation and wake every task waiting in `cancelled`.
+    pub fn cancel(&self) {
+        self.state.cancelled.store(true, Ordering::SeqCst);
+        self.state.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.state.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once `cancel` has been called.
+    pub async fn cancelled(&self) {
+        loop {
+            // Register before checking so a cancel in between is not missed.
+            let notified = self.state.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Pid of the root process currently running under this token, if any.
+    pub fn process(&self) -> Option<u32> {
+        match self.state.process.load(Ordering::SeqCst) {
+            0 => None,
+            pid => Some(pid),
+        }
+    }
+
+    fn set_process(&self, pid: Option<u32>) {
+        self.state.process.store(pid.unwrap_or(0), Ordering::SeqCst);
+    }
+}
+
+/// How a streamed process ended.
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessExit {
+    /// Exit code, or None if the process was killed by a signal or cancelled.
+    pub code: Option<i32>,
+    pub cancelled: bool,
+}
+
+impl ProcessExit {
+    const CANCELLED: ProcessExit = ProcessExit {
+        code: None,
+        cancelled: true,
+    };
+
+    pub fn success(&self) -> bool {
+        !self.cancelled && self.code == Some(0)
+    }
+
+    /// Why the process counts as failed, or None if it succeeded.
+    fn failure(&self) -> Option<String> {
+        match self.code {
+            _ if self.cancelled => Some("Cancelled".to_string()),
+            Some(0) => None,
+            Some(code) => Some(format!("Exited with code {}", code)),
+            None => Some("Terminated by a signal".to_string()),
+        }
+    }
+}
+
+/// How a finished build ended, sent once through the handle's completion channel.
+#[derive(Debug, Clone)]
+pub struct BuildOutcome {
+    pub success: bool,
+    /// Exit code of the last process the build ran, if it exited on its own.
+    pub exit_code: Option<i32>,
+    pub duration: Duration,
+    /// Why the build failed; None on success.
+    pub failure: Option<String>,
+}
+
+/// What a running build reports to the UI, in order.
+#[derive(Debug, Clone)]
+pub enum BuildEvent {
+    /// A line of output, or a message from Stellar about the build.
+    Line {
+        phase: BuildPhase,
+        stream: OutputStream,
+        text: String,
+    },
+    PhaseStarted(BuildPhase),
+    PhaseFinished {
+        phase: BuildPhase,
+        success: bool,
+        /// Exit code of the phase's last process, if it ran one that exited on its own.
+        exit_code: Option<i32>,
+    },
+    /// UBT's `[done/total]` action counter, with the action that just finished and its
+    /// duration in seconds when UBT reports it.
+    Progress {
+        done: usize,
+        total: usize,
+        action: Option<(String, f64)>,
+    },
+}
+
+/// Receives the output of a streamed process and Stellar's messages about it.
+pub trait OutputSink: Clone + Send + 'static {
+    fn line(&self, stream: OutputStream, text: String);
+
+    /// A message from Stellar itself rather than the process.
+    fn note(&self, text: impl Into<String>) {
+        self.line(OutputStream::Stellar, text.into());
+    }
+}
+
+/// Plain text output, as used by launch sessions.
+impl OutputSink for mpsc::UnboundedSender<String> {
+    fn line(&self, _stream: OutputStream, text: String) {
+        let _ = self.send(text);
+    }
+}
+
+/// Sends the events of one build phase.
+#[derive(Clone)]
+struct BuildLog {
+    tx: mpsc::UnboundedSender<BuildEvent>,
+    phase: BuildPhase,
+}
+
+impl BuildLog {
+    /// The same channel, reporting as `phase`.
+    fn with_phase(&self, phase: BuildPhase) -> BuildLog {
+        BuildLog {
+            tx: self.tx.clone(),
+            phase,
+        }
+    }
+
+    fn started(&self) {
+        let _ = self.tx.send(BuildEvent::PhaseStarted(self.phase));
+    }
+
+    fn finished(&self, success: bool, exit_code: Option<i32>) {
+        let _ = self.tx.send(BuildEvent::PhaseFinished {
+            phase: self.phase,
+            success,
+            exit_code,
+        });
+    }
+
+    fn finished_with(&self, exit: &ProcessExit) {
+        self.finished(exit.success(), exit.code);
+    }
+}
+
+impl OutputSink for BuildLog {
+    fn line(&self, stream: OutputStream, text: String) {
+        if stream != OutputStream::Stellar {
+            if let Some((done, total)) = parse_action_counter(&text) {
+                let _ = self.tx.send(BuildEvent::Progress {
+                    done,
+                    total,
+                    action: parse_action_duration(&text),
+                });
+            }
+        }
+        let _ = self.tx.send(BuildEvent::Line {
+            phase: self.phase,
+            stream,
+            text,
+        });
+    }
 }
 
 /// Handle to a running build process.
 pub struct BuildHandle {
-    finished: Arc<AtomicBool>,
-    success: Arc<AtomicBool>,
-    cancel_flag: Arc<AtomicBool>,
+    cancel: CancelToken,
+    done_rx: oneshot::Receiver<BuildOutcome>,
+    /// Outcome received by `finished` and not yet taken by `try_finished`.
+    outcome: Option<BuildOutcome>,
 }
 
 impl BuildHandle {
-    /// Non-blocking check: returns Some(success) if finished.
-    pub fn try_finished(&self) -> Option<bool> {
-        if self.finished.load(Ordering::Relaxed) {
-            Some(self.success.load(Ordering::Relaxed))
-        } else {
-            None
+    /// Non-blocking check: returns the outcome once, when the build has finished.
+    pub fn try_finished(&mut self) -> Option<BuildOutcome> {
+        if let Some(outcome) = self.outcome.take() {
+            return Some(outcome);
         }
+        match self.done_rx.try_recv() {
+            Ok(outcome) => Some(outcome),
+            Err(oneshot::error::TryRecvError::Empty) => None,
+            Err(oneshot::error::TryRecvError::Closed) => Some(BuildOutcome {
+                success: false,
+                exit_code: None,
+                duration: Duration::ZERO,
+                failure: Some("Build task ended unexpectedly".to_string()),
+            }),
+        }
+    }
+
+    /// Resolves when the build finishes; the outcome is then available from
+    /// `try_finished`. Safe to use in `select!`. Never resolves again afterwards.
+    pub async fn finished(&mut self) {
+        if self.outcome.is_some() || self.done_rx.is_terminated() {
+            return std::future::pending().await;
+        }
+        let outcome = (&mut self.done_rx).await.unwrap_or(BuildOutcome {
+            success: false,
+            exit_code: None,
+            duration: Duration::ZERO,
+            failure: Some("Build task ended unexpectedly".to_string()),
+        });
+        self.outcome = Some(outcome);
     }
 
     /// Signal the build to cancel.
     pub fn cancel(&self) {
-        self.cancel_flag.store(true, Ordering::Relaxed);
+        self.cancel.cancel();
+    }
+
+    /// True once the outcome has arrived, whether or not it was taken yet.
+    pub fn is_finished(&self) -> bool {
+        self.outcome.is_some() || self.done_rx.is_terminated()
+    }
+
+    /// Pid of the process the build is currently running, if any.
+    pub fn process(&self) -> Option<u32> {
+        self.cancel.process()
     }
 }
 
 /// Derive the editor target name from a .uproject path.
-/// First scans Source/ for *Editor.Target.cs files. If exactly one is found, use it.
+/// First scans Source/ for editor target rules files. If exactly one is found, use it.
 /// If the .uproject-derived name matches one, use it.
 /// Returns Err with a list of candidates if ambiguous and no match.
 pub fn derive_editor_target(project_path: &str) -> Result<String, String> {
@@ -75,30 +316,55 @@ pub fn derive_editor_target(project_path: &str) -> Result<String, String> {
     ))
 }
 
-/// Scan a Source/ directory for files matching *Editor.Target.cs and return target names.
-fn scan_editor_targets(source_dir: &Path) -> Vec<String> {
-    let mut targets = Vec::new();
-    if !source_dir.is_dir() {
-        return targets;
-    }
+/// Recursively scan a Source/ directory for `*.Target.cs` files and read each target's type.
+fn scan_targets(source_dir: &Path, targets: &mut Vec<BuildTarget>) {
+    let Ok(entries) = std::fs::read_dir(source_dir) else {
+        return;
+    };
 
-    if let Ok(entries) = std::fs::read_dir(source_dir) {
-        for entry in entries.flatten() {
-            let file_name = entry.file_name().to_string_lossy().to_string();
-            if file_name.ends_with("Editor.Target.cs") {
-                // Strip ".Target.cs" to get the target name
-                if let Some(name) = file_name.strip_suffix(".Target.cs") {
-                    targets.push(name.to_string());
-                }
-            }
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            scan_targets(&path, targets);
+            continue;
+        }
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        // Strip ".Target.cs" to get the target name
+        if let Some(name) = file_name.strip_suffix(".Target.cs") {
+            let contents = std::fs::read_to_string(&path).unwrap_or_default();
+            targets.push(BuildTarget {
+                name: name.to_string(),
+                kind: parse_target_kind(&contents).unwrap_or_else(|| guess_target_kind(name)),
+            });
         }
     }
+}
 
-    targets
+/// Read the `Type = TargetType.X` assignment from a target rules file.
+fn parse_target_kind(contents: &str) -> Option<TargetKind> {
+    static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = RE.get_or_init(|| regex::Regex::new(r"\bType\s*=\s*TargetType\.(\w+)").unwrap());
+    re.captures(contents)
+        .and_then(|caps| caps.get(1))
+        .and_then(|m| TargetKind::from_rules_name(m.as_str()))
 }
 
-/// Discover editor targets by scanning `<ProjectDir>/Source/*Editor.Target.cs`.
-pub fn discover_editor_targets(project_path: &str) -> Result<Vec<String>, String> {
+/// Fall back to the naming convention when the rules file does not set a type.
+fn guess_target_kind(name: &str) -> TargetKind {
+    if name.ends_with("Editor") {
+        TargetKind::Editor
+    } else if name.ends_with("Server") {
+        TargetKind::Server
+    } else if name.ends_with("Client") {
+        TargetKind::Client
+    } else {
+        TargetKind::Game
+    }
+}
+
+/// Discover all build targets by scanning `<ProjectDir>/Source/**/*.Target.cs`.
+/// Results are sorted by target type, then name.
+pub fn discover_targets(project_path: &str) -> Result<Vec<BuildTarget>, String> {
     let path = PathBuf::from(project_path);
     if !path.exists() {
         return Err(format!("Project file not found: {}", project_path));
@@ -109,10 +375,20 @@ pub fn discover_editor_targets(project_path: &str) -> Result<Vec<String>, String
         .ok_or_else(|| "Cannot determine project directory".to_string())?;
     let source_dir = project_dir.join("Source");
 
-    let mut editor_targets = scan_editor_targets(&source_dir);
-    editor_targets.sort();
-    editor_targets.dedup();
-    Ok(editor_targets)
+    let mut targets = Vec::new();
+    scan_targets(&source_dir, &mut targets);
+    targets.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+    targets.dedup();
+    Ok(targets)
+}
+
+/// Discover editor targets (`Type = TargetType.Editor`) in the project's Source/ directory.
+pub fn discover_editor_targets(project_path: &str) -> Result<Vec<String>, String> {
+    Ok(discover_targets(project_path)?
+        .into_iter()
+        .filter(|t| t.kind == TargetKind::Editor)
+        .map(|t| t.name)
+        .collect())
 }
 
 pub fn looks_like_target_error(line: &str) -> bool {
@@ -125,151 +401,418 @@ pub fn looks_like_target_error(line: &str) -> bool {
         && lower.contains("target")
 }
 
+/// Parse a UBT action counter such as `[123/456] Compile Foo.cpp` into `(done, total)`.
+pub fn parse_action_counter(line: &str) -> Option<(usize, usize)> {
+    static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = RE.get_or_init(|| regex::Regex::new(r"^\s*\[(\d+)/(\d+)\]").unwrap());
+    let caps = re.captures(line)?;
+    let done = caps[1].parse().ok()?;
+    let total: usize = caps[2].parse().ok()?;
+    (total > 0 && done <= total).then_some((done, total))
+}
+
+/// Parse an action line that reports its own duration, e.g.
+/// `[12/40] Compile [x64] Foo.cpp (3.42s)`, into `(action, seconds)`.
+pub fn parse_action_duration(line: &str) -> Option<(String, f64)> {
+    static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = RE.get_or_init(|| {
+        regex::Regex::new(r"^\s*\[\d+/\d+\]\s*(.+?)\s*\((\d+(?:\.\d+)?)\s*s\)\s*$").unwrap()
+    });
+    let caps = re.captures(line)?;
+    Some((caps[1].to_string(), caps[2].parse().ok()?))
+}
+
+/// Binaries under the project's (and its plugins', nested ones included) `Binaries`
+/// folders written since `since`.
+pub fn find_new_binaries(project_path: &str, since: std::time::SystemTime) -> Vec<PathBuf> {
+    let Some(project_dir) = Path::new(project_path).parent() else {
+        return Vec::new();
+    };
+    let mut roots = vec![project_dir.join("Binaries")];
+    roots.extend(
+        find_plugin_dirs(project_dir)
+            .into_iter()
+            .map(|plugin| plugin.join("Binaries")),
+    );
+
+    let mut found = Vec::new();
+    let mut stack = roots;
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            let is_binary = match path.extension().and_then(|e| e.to_str()) {
+                Some(ext) => matches!(ext, "exe" | "dll" | "so" | "dylib"),
+                None => is_executable(&metadata),
+            };
+            let modified = metadata.modified().ok();
+            if is_binary && modified.is_some_and(|m| m >= since) {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    found
+}
+
+/// Whether an extensionless file is a program: executable on unix, never on Windows.
+fn is_executable(metadata: &std::fs::Metadata) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.permissions().mode() & 0o111 != 0
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = metadata;
+        false
+    }
+}
+
 pub fn is_ambiguous_target_error(err: &str) -> bool {
     err.contains("Multiple editor targets found")
 }
 
-/// Spawn a build as a background tokio task.
-/// Log lines are sent through `tx`. Returns a handle to check status / cancel.
+/// Spawn a build as a background tokio task, running its main step with `backend`.
+/// Output and progress are sent through `tx` as events. Returns a handle to check
+/// status / cancel.
 pub fn spawn_build(
     project_path: String,
     engine_path: String,
-    editor_target_override: Option<String>,
-    tx: mpsc::UnboundedSender<String>,
-    mode: BuildMode,
+    settings: BuildSettings,
+    backend: Box<dyn BuildBackend>,
+    backup_max_age_days: u32,
+    cancel_timeout: Duration,
+    tx: mpsc::UnboundedSender<BuildEvent>,
 ) -> Result<BuildHandle, String> {
-    let ubt_dll = PathBuf::from(&engine_path)
-        .join("Engine/Binaries/DotNET/UnrealBuildTool/UnrealBuildTool.dll");
-
-    if !ubt_dll.exists() {
-        return Err(format!(
-            "UnrealBuildTool not found at {}",
-            ubt_dll.display()
-        ));
-    }
+    let mode = settings.mode;
+    let project_files = if mode == BuildMode::CleanRebuild {
+        Some(crate::backend::unreal_build_tool(
+            &engine_path,
+            UbtTask::ProjectFiles,
+        )?)
+    } else {
+        None
+    };
 
-    let target_name = editor_target_override
+    let target_override = settings
+        .target
+        .clone()
         .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .map(Ok)
-        .unwrap_or_else(|| derive_editor_target(&project_path))?;
+        .filter(|s| !s.is_empty());
+    let target_name = if settings.command.is_some() {
+        // Commands only see the target through `{target}`; an ambiguous one is left empty.
+        target_override
+            .map(Ok)
+            .unwrap_or_else(|| derive_editor_target(&project_path))
+            .unwrap_or_default()
+    } else if mode == BuildMode::GenerateProjectFiles {
+        // Project files cover every target of the project.
+        String::new()
+    } else if mode == BuildMode::Package {
+        // BuildCookRun picks the game target itself; only forward non-editor overrides.
+        target_override
+            .filter(|t| !t.ends_with("Editor"))
+            .unwrap_or_default()
+    } else {
+        target_override
+            .map(Ok)
+            .unwrap_or_else(|| derive_editor_target(&project_path))?
+    };
     let project_dir = PathBuf::from(&project_path)
         .parent()
         .map(|p| p.to_path_buf());
 
-    let cmd_display = match mode {
-        BuildMode::Standard => format!(
-            "dotnet \"{}\" {} Win64 Development -Project=\"{}\" -WaitMutex",
-            ubt_dll.display(),
-            target_name,
-            project_path
-        ),
-        BuildMode::CleanRebuild => format!(
-            "Clean Rebuild -> clean temp files, regenerate project files, then: dotnet \"{}\" {} Win64 Development -Project=\"{}\" -WaitMutex",
-            ubt_dll.display(),
-            target_name,
-            project_path
-        ),
+    let job = BuildJob {
+        engine_path,
+        target_name,
+        settings,
+        project_path,
+        project_dir,
+        backend,
+        project_files,
+        backup_max_age_days,
+        cancel_timeout,
     };
-    let _ = tx.send(format!("Running: {}", cmd_display));
 
-    let finished = Arc::new(AtomicBool::new(false));
-    let success = Arc::new(AtomicBool::new(false));
-    let cancel_flag = Arc::new(AtomicBool::new(false));
+    let cmd_display = job.backend.command(&job).display();
+    let cmd_display = if mode == BuildMode::CleanRebuild {
+        format!(
+            "Clean Rebuild -> clean temp files, regenerate project files, then: {}",
+            cmd_display
+        )
+    } else {
+        cmd_display
+    };
+    let env_display: String = job
+        .settings
+        .env
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\" ", key, value))
+        .collect();
+    let log = BuildLog {
+        tx,
+        phase: job.backend.phase(),
+    };
+    let mut toolchains = Vec::new();
+    for backend in std::iter::once(&job.backend).chain(&job.project_files) {
+        if let Some(toolchain) = backend.toolchain() {
+            if !toolchains.contains(&toolchain) {
+                log.note(format!("Toolchain: {}", toolchain));
+                toolchains.push(toolchain);
+            }
+        }
+    }
+    log.note(format!(
+        "Running {}: {}{}",
+        job.backend.name(),
+        env_display,
+        cmd_display
+    ));
+
+    let cancel = CancelToken::new();
+    let (done_tx, done_rx) = oneshot::channel();
 
     let handle = BuildHandle {
-        finished: finished.clone(),
-        success: success.clone(),
-        cancel_flag: cancel_flag.clone(),
+        cancel: cancel.clone(),
+        done_rx,
+        outcome: None,
     };
 
     tokio::spawn(async move {
-        let result = run_build_process(
-            &ubt_dll,
-            &target_name,
-            &project_path,
-            project_dir.as_ref(),
-            tx.clone(),
-            cancel_flag,
-            mode,
-        )
-        .await;
-
-        match result {
-            Ok(exit_success) => {
-                success.store(exit_success, Ordering::Relaxed);
-            }
+        let started = Instant::now();
+        let outcome = match run_build_process(&job, &log, &cancel).await {
+            Ok(exit) => BuildOutcome {
+                success: exit.success(),
+                exit_code: exit.code,
+                duration: started.elapsed(),
+                failure: exit.failure(),
+            },
             Err(e) => {
-                let _ = tx.send(format!("Build error: {}", e));
-                success.store(false, Ordering::Relaxed);
+                log.note(format!("Build error: {}", e));
+                BuildOutcome {
+                    success: false,
+                    exit_code: None,
+                    duration: started.elapsed(),
+                    failure: Some(e),
+                }
             }
-        }
-        finished.store(true, Ordering::Relaxed);
+        };
+        let _ = done_tx.send(outcome);
     });
 
     Ok(handle)
 }
 
 async fn run_build_process(
-    ubt_dll: &PathBuf,
-    target_name: &str,
-    project_path: &str,
-    project_dir: Option<&PathBuf>,
-    tx: mpsc::UnboundedSender<String>,
-    cancel_flag: Arc<AtomicBool>,
-    mode: BuildMode,
-) -> Result<bool, String> {
-    use tokio::io::{AsyncBufReadExt, BufReader};
-    use tokio::process::Command;
-
-    if mode == BuildMode::CleanRebuild {
-        if cancel_flag.load(Ordering::Relaxed) {
-            let _ = tx.send("Clean rebuild cancelled before starting.".to_string());
-            return Ok(false);
+    job: &BuildJob,
+    log: &BuildLog,
+    cancel: &CancelToken,
+) -> Result<ProcessExit, String> {
+    if !job.settings.pre_build_hooks.is_empty() {
+        let log = log.with_phase(BuildPhase::PreBuildHooks);
+        log.started();
+        for hook in &job.settings.pre_build_hooks {
+            if cancel.is_cancelled() {
+                log.note("Build cancelled during pre-build hooks.");
+                log.finished_with(&ProcessExit::CANCELLED);
+                return Ok(ProcessExit::CANCELLED);
+            }
+            let exit = run_hook(job, hook, None, &log, cancel).await?;
+            if !exit.success() {
+                log.finished_with(&exit);
+                if exit.cancelled {
+                    return Ok(exit);
+                }
+                return Err(format!("Pre-build hook failed, aborting build: {}", hook));
+            }
         }
+        log.finished(true, Some(0));
+    }
 
-        let _ = tx.send("Clean rebuild: removing temporary project files...".to_string());
-        clean_project_artifacts(project_path, project_dir, &tx).await?;
+    let result = run_build_steps(job, log, cancel).await;
+    if cancel.is_cancelled() {
+        return result;
+    }
 
-        if cancel_flag.load(Ordering::Relaxed) {
-            let _ = tx.send("Clean rebuild cancelled before project file generation.".to_string());
-            return Ok(false);
+    let success = matches!(result, Ok(exit) if exit.success());
+    if !job.settings.post_build_hooks.is_empty() {
+        let log = log.with_phase(BuildPhase::PostBuildHooks);
+        log.started();
+        let mut all_passed = true;
+        for hook in &job.settings.post_build_hooks {
+            if cancel.is_cancelled() {
+                all_passed = false;
+                break;
+            }
+            match run_hook(job, hook, Some(success), &log, cancel).await {
+                Ok(exit) if exit.success() => {}
+                Ok(_) => {
+                    all_passed = false;
+                    log.note(format!("Post-build hook failed: {}", hook));
+                }
+                Err(e) => {
+                    all_passed = false;
+                    log.note(format!("Post-build hook error: {}", e));
+                }
+            }
         }
+        log.finished(all_passed, None);
+    }
+
+    result
+}
+
+/// Run one hook command through the platform shell, after expanding its placeholders.
+/// `result` is None for pre-build hooks and the build outcome for post-build hooks.
+async fn run_hook(
+    job: &BuildJob,
+    hook: &str,
+    result: Option<bool>,
+    log: &BuildLog,
+    cancel: &CancelToken,
+) -> Result<ProcessExit, String> {
+    let command = expand_placeholders(job, hook, result);
+    log.note(format!("Hook: {}", command));
 
-        let _ = tx.send("Clean rebuild: regenerating project files...".to_string());
-        regenerate_project_files(ubt_dll, project_path, project_dir, &tx).await?;
+    let mut cmd = crate::backend::shell_command(&command).to_command();
+    cmd.envs(&job.settings.env);
+    if let Some(dir) = &job.project_dir {
+        cmd.current_dir(dir);
     }
 
-    let mut cmd = Command::new("dotnet");
-    cmd.arg(ubt_dll)
-        .arg(target_name)
-        .arg("Win64")
-        .arg("Development")
-        .arg(format!("-Project={}", project_path))
-        .arg("-WaitMutex")
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .kill_on_drop(true);
+    run_streamed(cmd, log, cancel, job.cancel_timeout).await
+}
+
+/// Substitute `{project}`, `{project_dir}`, `{engine}`, `{target}`, `{platform}`,
+/// `{configuration}` and `{result}` (post-build hooks only) in a hook or preset command.
+/// Each value is quoted for the shell, so placeholders must not be quoted again.
+pub fn expand_placeholders(job: &BuildJob, hook: &str, result: Option<bool>) -> String {
+    use crate::backend::shell_quote;
+
+    let project_dir = job
+        .project_dir
+        .as_ref()
+        .map(|d| d.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let result = match result {
+        Some(true) => "success",
+        Some(false) => "failed",
+        None => "",
+    };
+    hook.replace("{project_dir}", &shell_quote(&project_dir))
+        .replace("{project}", &shell_quote(&job.project_path))
+        .replace("{engine}", &shell_quote(&job.engine_path))
+        .replace("{target}", &shell_quote(&job.target_name))
+        .replace("{platform}", job.settings.platform.ubt_name())
+        .replace("{configuration}", job.settings.configuration.ubt_name())
+        .replace("{result}", result)
+}
+
+/// The clean / project-file steps of a build and its backend's main step, without
+/// hooks. `log` reports as the main step's phase.
+async fn run_build_steps(
+    job: &BuildJob,
+    log: &BuildLog,
+    cancel: &CancelToken,
+) -> Result<ProcessExit, String> {
+    let project_path = job.project_path.as_str();
+    let project_dir = job.project_dir.as_ref();
+
+    if job.settings.mode == BuildMode::CleanRebuild {
+        let clean_log = log.with_phase(BuildPhase::Clean);
+        if cancel.is_cancelled() {
+            clean_log.note("Clean rebuild cancelled before starting.");
+            return Ok(ProcessExit::CANCELLED);
+        }
+
+        clean_log.started();
+        clean_log.note("Clean rebuild: removing temporary project files...");
+        let cleaned = clean_project_artifacts(
+            project_path,
+            project_dir,
+            &job.settings.clean_dirs,
+            job.settings.clean_backup.then_some(job.backup_max_age_days),
+            &clean_log,
+            cancel,
+        )
+        .await;
+        clean_log.finished(matches!(cleaned, Ok(true)), None);
+        if !cleaned? {
+            return Ok(ProcessExit::CANCELLED);
+        }
+
+        let generate_log = log.with_phase(BuildPhase::GenerateProjectFiles);
+        if cancel.is_cancelled() {
+            generate_log.note("Clean rebuild cancelled before project file generation.");
+            return Ok(ProcessExit::CANCELLED);
+        }
+
+        generate_log.started();
+        generate_log.note("Clean rebuild: regenerating project files...");
+        if !regenerate_project_files(job, &generate_log, cancel).await? {
+            return Ok(ProcessExit::CANCELLED);
+        }
+    }
 
+    let mut cmd = job.backend.command(job).to_command();
+    cmd.envs(&job.settings.env);
     if let Some(dir) = project_dir {
         cmd.current_dir(dir);
     }
 
+    log.started();
+    let exit = run_streamed(cmd, log, cancel, job.cancel_timeout).await;
+    match &exit {
+        Ok(exit) => log.finished_with(exit),
+        Err(_) => log.finished(false, None),
+    }
+    exit
+}
+
+/// Spawn `cmd`, stream its stdout/stderr into `out` line by line and wait for it.
+/// If `cancel` fires, the process and everything it spawned is interrupted and, after
+/// `cancel_timeout`, force-killed.
+pub async fn run_streamed<S: OutputSink>(
+    mut cmd: tokio::process::Command,
+    out: &S,
+    cancel: &CancelToken,
+    cancel_timeout: Duration,
+) -> Result<ProcessExit, String> {
+    use tokio::io::{AsyncBufReadExt, BufReader};
+
+    cmd.stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true);
+    crate::process::isolate(&mut cmd);
+
+    let program = cmd.as_std().get_program().to_string_lossy().to_string();
     let mut child = cmd
         .spawn()
-        .map_err(|e| format!("Failed to spawn dotnet: {}", e))?;
+        .map_err(|e| format!("Failed to spawn {}: {}", program, e))?;
+    cancel.set_process(child.id());
 
     // Stream stdout
     let stdout = child.stdout.take();
     let stderr = child.stderr.take();
-    let tx_out = tx.clone();
-    let tx_err = tx.clone();
+    let out_stdout = out.clone();
+    let out_stderr = out.clone();
 
     let stdout_task = tokio::spawn(async move {
         if let Some(stdout) = stdout {
             let mut reader = BufReader::new(stdout).lines();
             while let Ok(Some(line)) = reader.next_line().await {
-                let _ = tx_out.send(line);
+                out_stdout.line(OutputStream::Stdout, line);
             }
         }
     });
@@ -278,122 +821,448 @@ async fn run_build_process(
         if let Some(stderr) = stderr {
             let mut reader = BufReader::new(stderr).lines();
             while let Ok(Some(line)) = reader.next_line().await {
-                let _ = tx_err.send(line);
+                out_stderr.line(OutputStream::Stderr, line);
             }
         }
     });
 
-    // Poll for completion or cancellation
-    loop {
-        if cancel_flag.load(Ordering::Relaxed) {
-            let _ = child.kill().await;
-            let _ = tx.send("Build process killed.".to_string());
-            stdout_task.abort();
-            stderr_task.abort();
-            return Ok(false);
-        }
-        match child.try_wait() {
-            Ok(Some(status)) => {
-                // Wait for output tasks to finish draining
-                let _ = stdout_task.await;
-                let _ = stderr_task.await;
-                return Ok(status.success());
-            }
-            Ok(None) => {
-                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    // Wait for exit or cancellation, whichever comes first.
+    let status = tokio::select! {
+        status = child.wait() => Some(status),
+        _ = cancel.cancelled() => None,
+    };
+    let Some(status) = status else {
+        stop_process_tree(&mut child, cancel_timeout, out).await;
+        cancel.set_process(None);
+        stdout_task.abort();
+        stderr_task.abort();
+        return Ok(ProcessExit::CANCELLED);
+    };
+    cancel.set_process(None);
+    let status = status.map_err(|e| format!("Error waiting for process: {}", e))?;
+
+    // Wait for output tasks to finish draining
+    let _ = stdout_task.await;
+    let _ = stderr_task.await;
+    Ok(ProcessExit {
+        code: status.code(),
+        cancelled: false,
+    })
+}
+
+/// Stop `child` and its whole process tree: interrupt every process, give them
+/// `grace` to exit, then force-kill whatever is left. Reports each step to the log.
+async fn stop_process_tree(
+    child: &mut tokio::process::Child,
+    grace: Duration,
+    out: &impl OutputSink,
+) {
+    use crate::process;
+
+    let Some(pid) = child.id() else {
+        // Already exited and reaped; nothing left to signal.
+        return;
+    };
+    let tree = process::list_tree(pid).await;
+    out.note(format!(
+        "Cancelling: interrupting {} processes: {}",
+        tree.len(),
+        process::describe(&tree)
+    ));
+    process::interrupt_tree(pid).await;
+
+    // Wait for the root, then for children that outlive it (compilers finishing a file).
+    let deadline = tokio::time::Instant::now() + grace;
+    let _ = tokio::time::timeout_at(deadline, child.wait()).await;
+    let mut remaining = process::list_tree(pid).await;
+    while !remaining.is_empty() && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        remaining = process::list_tree(pid).await;
+    }
+
+    let stopped: Vec<(u32, String)> = tree
+        .iter()
+        .filter(|p| !remaining.contains(p))
+        .cloned()
+        .collect();
+    if !stopped.is_empty() {
+        out.note(format!(
+            "Stopped after interrupt: {}",
+            process::describe(&stopped)
+        ));
+    }
+    if !remaining.is_empty() {
+        out.note(format!(
+            "Force-killing {} processes still running after {}s: {}",
+            remaining.len(),
+            grace.as_secs(),
+            process::describe(&remaining)
+        ));
+        process::kill_tree(pid, &remaining).await;
+    }
+    let _ = child.kill().await;
+    out.note("Process tree stopped.");
+}
+
+/// Folders the clean dialog offers, relative to the project directory. Plugin folders
+/// are offered as the `Plugins/**/...` entries covering every plugin. Only entries that
+/// match an existing folder are listed.
+pub fn clean_candidates(project_dir: &Path) -> Vec<String> {
+    let mut candidates: Vec<String> = [
+        "Binaries",
+        "Intermediate",
+        "Saved",
+        "DerivedDataCache",
+        ".vs",
+        PLUGIN_BINARIES,
+        PLUGIN_INTERMEDIATE,
+    ]
+    .iter()
+    .map(|d| d.to_string())
+    .collect();
+
+    candidates.retain(|c| !resolve_clean_dir(project_dir, c).is_empty());
+    candidates
+}
+
+/// Every plugin folder (one holding a `.uplugin` file) under the project's `Plugins`
+/// directory, including plugins nested in category folders.
+pub fn find_plugin_dirs(project_dir: &Path) -> Vec<PathBuf> {
+    let mut plugins = Vec::new();
+    let mut stack = vec![project_dir.join("Plugins")];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut subdirs = Vec::new();
+        let mut is_plugin = false;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                subdirs.push(path);
+            } else if path.extension().is_some_and(|e| e == "uplugin") {
+                is_plugin = true;
             }
-            Err(e) => {
-                return Err(format!("Error waiting for process: {}", e));
+        }
+        if is_plugin {
+            plugins.push(dir);
+        }
+        // Generated and asset folders never contain plugins; skip them to keep the walk cheap.
+        subdirs.retain(|d| {
+            !matches!(
+                d.file_name().and_then(|n| n.to_str()),
+                Some("Binaries" | "Intermediate" | "Content" | "Source" | "Resources")
+            )
+        });
+        stack.extend(subdirs);
+    }
+    plugins.sort();
+    plugins
+}
+
+/// Existing folders a clean entry refers to: the folder itself, or for the
+/// `Plugins/**/...` entries the matching folder of every plugin.
+pub fn resolve_clean_dir(project_dir: &Path, entry: &str) -> Vec<PathBuf> {
+    let dirs = match entry.strip_prefix("Plugins/**/") {
+        Some(name) => find_plugin_dirs(project_dir)
+            .into_iter()
+            .map(|plugin| plugin.join(name))
+            .collect(),
+        None => vec![project_dir.join(entry)],
+    };
+    dirs.into_iter().filter(|d| d.is_dir()).collect()
+}
+
+/// Total size in bytes of the files under `path` (or of `path` itself if it is a file).
+pub fn dir_size(path: &Path) -> u64 {
+    if path.is_file() {
+        return path.metadata().map(|m| m.len()).unwrap_or(0);
+    }
+    let mut total = 0;
+    let mut stack = vec![path.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            match entry.file_type() {
+                Ok(t) if t.is_dir() => stack.push(entry.path()),
+                Ok(_) => total += entry.metadata().map(|m| m.len()).unwrap_or(0),
+                Err(_) => {}
             }
         }
     }
+    total
 }
 
+/// Every existing folder and file a clean of `dirs` removes: the resolved clean folders
+/// plus the generated solution files.
+pub fn clean_paths(project_path: &str, project_dir: &Path, dirs: &[String]) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = dirs
+        .iter()
+        .flat_map(|entry| resolve_clean_dir(project_dir, entry))
+        .collect();
+
+    let project_file = PathBuf::from(project_path);
+    let mut files = vec![project_file.with_extension("sln")];
+    if let Some(stem) = project_file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+    {
+        files.push(project_dir.join(format!("{}.sln", stem)));
+    }
+    files.dedup();
+    paths.extend(files.into_iter().filter(|f| f.is_file()));
+    paths
+}
+
+/// Folder inside the project directory that holds the backups of safe-mode cleans,
+/// one timestamped subfolder per clean.
+pub const CLEAN_BACKUP_DIR: &str = ".stellar-backup";
+
+/// File in a backup folder listing the moved paths, relative to the project directory.
+const BACKUP_MANIFEST: &str = "moved.txt";
+
+/// Remove the selected clean folders and the generated solution files, or move them into
+/// a new backup folder when `backup_max_age_days` is set (pruning older backups first).
+/// Returns false if the build was cancelled between two deletions.
 async fn clean_project_artifacts(
     project_path: &str,
     project_dir: Option<&PathBuf>,
-    tx: &mpsc::UnboundedSender<String>,
-) -> Result<(), String> {
+    dirs_to_remove: &[String],
+    backup_max_age_days: Option<u32>,
+    log: &BuildLog,
+    cancel: &CancelToken,
+) -> Result<bool, String> {
     let Some(project_dir) = project_dir else {
         return Err("Could not determine project directory for clean rebuild.".to_string());
     };
 
-    let dirs_to_remove = ["Binaries", "Intermediate", "Saved", ".vs"];
-    for dir_name in dirs_to_remove {
-        let full = project_dir.join(dir_name);
-        if full.exists() {
-            let _ = tx.send(format!("Removing directory: {}", full.display()));
-            tokio::fs::remove_dir_all(&full)
+    let paths = clean_paths(project_path, project_dir, dirs_to_remove);
+
+    let backup = match backup_max_age_days {
+        Some(max_age_days) => {
+            for pruned in prune_clean_backups(project_dir, max_age_days).await {
+                log.note(format!("Pruned old clean backup: {}", pruned.display()));
+            }
+            let dir = project_dir
+                .join(CLEAN_BACKUP_DIR)
+                .join(chrono::Local::now().format("%Y%m%d-%H%M%S").to_string());
+            log.note(format!("Moving cleaned files to {}", dir.display()));
+            Some(dir)
+        }
+        None => None,
+    };
+
+    let mut moved = Vec::new();
+    for (removed, full) in paths.iter().enumerate() {
+        if cancel.is_cancelled() {
+            log.note(format!(
+                "Clean rebuild cancelled after cleaning {} of {} paths.",
+                removed,
+                paths.len()
+            ));
+            return Ok(false);
+        }
+
+        let Some(backup) = &backup else {
+            log.note(format!("Removing: {}", full.display()));
+            let result = if full.is_dir() {
+                tokio::fs::remove_dir_all(full).await
+            } else {
+                tokio::fs::remove_file(full).await
+            };
+            result.map_err(|e| format!("Failed to remove {}: {}", full.display(), e))?;
+            continue;
+        };
+
+        let relative = full.strip_prefix(project_dir).unwrap_or(full);
+        let dest = backup.join(relative);
+        log.note(format!("Moving to backup: {}", relative.display()));
+        if let Some(parent) = dest.parent() {
+            tokio::fs::create_dir_all(parent)
                 .await
-                .map_err(|e| format!("Failed to remove {}: {}", full.display(), e))?;
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
         }
+        tokio::fs::rename(full, &dest)
+            .await
+            .map_err(|e| format!("Failed to move {}: {}", full.display(), e))?;
+        // Keep the manifest current so a cancelled clean can still be restored.
+        moved.push(relative.to_string_lossy().to_string());
+        tokio::fs::write(backup.join(BACKUP_MANIFEST), moved.join("\n"))
+            .await
+            .map_err(|e| format!("Failed to write backup manifest: {}", e))?;
     }
 
-    let project_file = PathBuf::from(project_path);
-    let sln_from_project = project_file.with_extension("sln");
-    let mut files_to_remove = vec![sln_from_project];
+    log.note(match backup {
+        Some(_) => format!("Moved {} paths to the backup.", paths.len()),
+        None => format!("Removed {} paths.", paths.len()),
+    });
+    Ok(true)
+}
 
-    if let Some(stem) = project_file
-        .file_stem()
-        .map(|s| s.to_string_lossy().to_string())
-    {
-        files_to_remove.push(project_dir.join(format!("{}.sln", stem)));
+/// Remove clean backups older than `max_age_days` (0 keeps every backup). Returns the
+/// removed backup folders.
+async fn prune_clean_backups(project_dir: &Path, max_age_days: u32) -> Vec<PathBuf> {
+    let mut pruned = Vec::new();
+    if max_age_days == 0 {
+        return pruned;
     }
+    let cutoff = chrono::Local::now().naive_local() - chrono::Duration::days(max_age_days.into());
+    for (name, path) in list_clean_backups(project_dir) {
+        let Ok(taken) = chrono::NaiveDateTime::parse_from_str(&name, "%Y%m%d-%H%M%S") else {
+            continue;
+        };
+        if taken < cutoff && tokio::fs::remove_dir_all(&path).await.is_ok() {
+            pruned.push(path);
+        }
+    }
+    pruned
+}
 
-    for file in files_to_remove {
-        if file.exists() {
-            let _ = tx.send(format!("Removing file: {}", file.display()));
-            tokio::fs::remove_file(&file)
-                .await
-                .map_err(|e| format!("Failed to remove {}: {}", file.display(), e))?;
+/// Backup folders of a project as `(name, path)`, oldest first.
+fn list_clean_backups(project_dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(project_dir.join(CLEAN_BACKUP_DIR)) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<(String, PathBuf)> = entries
+        .flatten()
+        .filter(|e| e.path().join(BACKUP_MANIFEST).is_file())
+        .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+        .collect();
+    backups.sort();
+    backups
+}
+
+/// Put the files of the most recent clean backup back into the project, replacing what
+/// the rebuild regenerated, then delete the backup. Returns the restored paths.
+pub fn restore_last_clean(project_dir: &Path) -> Result<Vec<String>, String> {
+    let (name, backup) = list_clean_backups(project_dir)
+        .pop()
+        .ok_or_else(|| "No clean backup to restore.".to_string())?;
+    let manifest = std::fs::read_to_string(backup.join(BACKUP_MANIFEST))
+        .map_err(|e| format!("Failed to read backup {}: {}", name, e))?;
+
+    let mut restored = Vec::new();
+    for relative in manifest.lines().filter(|l| !l.is_empty()) {
+        let source = backup.join(relative);
+        let dest = project_dir.join(relative);
+        if !source.exists() {
+            continue;
+        }
+        if dest.is_dir() {
+            std::fs::remove_dir_all(&dest)
+                .map_err(|e| format!("Failed to replace {}: {}", dest.display(), e))?;
+        } else if dest.exists() {
+            std::fs::remove_file(&dest)
+                .map_err(|e| format!("Failed to replace {}: {}", dest.display(), e))?;
         }
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        std::fs::rename(&source, &dest)
+            .map_err(|e| format!("Failed to restore {}: {}", relative, e))?;
+        restored.push(relative.to_string());
     }
 
-    Ok(())
+    std::fs::remove_dir_all(&backup)
+        .map_err(|e| format!("Failed to remove backup {}: {}", backup.display(), e))?;
+    Ok(restored)
 }
 
+/// Regenerate the project files, streaming UBT's output into `log` and reporting the
+/// end of its phase. Returns false if the build was cancelled while generating (the
+/// generator is killed).
 async fn regenerate_project_files(
-    ubt_dll: &PathBuf,
-    project_path: &str,
-    project_dir: Option<&PathBuf>,
-    tx: &mpsc::UnboundedSender<String>,
-) -> Result<(), String> {
-    use tokio::process::Command;
-
-    let mut cmd = Command::new("dotnet");
-    cmd.arg(ubt_dll)
-        .arg("-ProjectFiles")
-        .arg(format!("-Project={}", project_path))
-        .arg("-Game")
-        .arg("-Engine");
+    job: &BuildJob,
+    log: &BuildLog,
+    cancel: &CancelToken,
+) -> Result<bool, String> {
+    let Some(backend) = &job.project_files else {
+        return Err("No project file generator for this job.".to_string());
+    };
+    let mut cmd = backend.command(job).to_command();
+    cmd.envs(&job.settings.env);
 
-    if let Some(dir) = project_dir {
+    if let Some(dir) = &job.project_dir {
         cmd.current_dir(dir);
     }
 
-    let output = cmd
-        .output()
-        .await
-        .map_err(|e| format!("Failed to regenerate project files: {}", e))?;
-
-    for line in String::from_utf8_lossy(&output.stdout).lines() {
-        if !line.trim().is_empty() {
-            let _ = tx.send(line.to_string());
+    let exit = match run_streamed(cmd, log, cancel, job.cancel_timeout).await {
+        Ok(exit) => exit,
+        Err(e) => {
+            log.finished(false, None);
+            return Err(format!("Failed to regenerate project files: {}", e));
         }
+    };
+    log.finished_with(&exit);
+    if exit.success() {
+        Ok(true)
+    } else if exit.cancelled {
+        log.note("Clean rebuild cancelled during project file generation.");
+        Ok(false)
+    } else {
+        Err(format!(
+            "Project file generation failed: {}",
+            exit.failure().unwrap_or_default()
+        ))
     }
+}
 
-    for line in String::from_utf8_lossy(&output.stderr).lines() {
-        if !line.trim().is_empty() {
-            let _ = tx.send(line.to_string());
+#[cfg(test)]
+impl BuildJob {
+    /// A job for inspecting the command lines backends derive from `settings`.
+    pub fn for_test(
+        project_path: &str,
+        target_name: &str,
+        settings: BuildSettings,
+        backend: Box<dyn BuildBackend>,
+    ) -> Self {
+        Self {
+            engine_path: String::new(),
+            target_name: target_name.to_string(),
+            settings,
+            project_path: project_path.to_string(),
+            project_dir: Path::new(project_path).parent().map(Path::to_path_buf),
+            backend,
+            project_files: None,
+            backup_max_age_days: 0,
+            cancel_timeout: Duration::ZERO,
         }
     }
+}
 
-    if output.status.success() {
-        Ok(())
-    } else {
-        Err(format!(
-            "Project file generation failed with status: {}",
-            output.status
-        ))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_action_counters() {
+        assert_eq!(
+            parse_action_counter("[12/40] Compile [x64] Foo.cpp"),
+            Some((12, 40))
+        );
+        assert_eq!(
+            parse_action_counter("  [40/40] Link Game.exe"),
+            Some((40, 40))
+        );
+        assert_eq!(parse_action_counter("[3/0] Compile Foo.cpp"), None);
+        assert_eq!(parse_action_counter("[41/40] Compile Foo.cpp"), None);
+        assert_eq!(parse_action_counter("Building 40 actions [12/40]"), None);
+    }
+
+    #[test]
+    fn parses_action_durations() {
+        assert_eq!(
+            parse_action_duration("[12/40] Compile [x64] Foo.cpp (3.42s)"),
+            Some(("Compile [x64] Foo.cpp".to_string(), 3.42))
+        );
+        assert_eq!(
+            parse_action_duration("[40/40] Link Game.exe (12 s)"),
+            Some(("Link Game.exe".to_string(), 12.0))
+        );
+        assert_eq!(parse_action_duration("[12/40] Compile Foo.cpp"), None);
+        assert_eq!(parse_action_duration("Total time (3.42s)"), None);
     }
 }
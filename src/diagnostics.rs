@@ -0,0 +1,204 @@
+use crate::types::{Diagnostic, DiagnosticSource, Severity};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// `C:\Proj\Source\Foo.cpp(42): error C2065: 'x': undeclared identifier`
+/// (also `(42,7)` and `fatal error C1083`).
+fn msvc_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"^\s*(.+?)\((\d+)(?:,(\d+))?\)\s*:\s*(fatal error|error|warning)\s+([A-Z]+\d+)\s*:\s*(.*)$",
+        )
+        .unwrap()
+    })
+}
+
+/// `/proj/Source/Foo.cpp:42:7: error: use of undeclared identifier 'x'`
+fn clang_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\s*(.+?):(\d+):(\d+):\s*(fatal error|error|warning):\s*(.*)$").unwrap()
+    })
+}
+
+/// `C:\Proj\Source\Foo.h(12): Error: Unrecognized type 'FBar'` from UnrealHeaderTool.
+fn uht_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^\s*(.+?)\((\d+)\)\s*:\s*(Error|Warning):\s*(.*)$").unwrap())
+}
+
+/// `ERROR: Could not find definition for module 'Foo'` or
+/// `UnrealBuildTool : error : ...` from UnrealBuildTool itself.
+fn ubt_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"^\s*(?:UnrealBuildTool\s*:\s*(error|warning)\s*:?|(ERROR|WARNING):)\s*(.+)$")
+            .unwrap()
+    })
+}
+
+fn severity(text: &str) -> Severity {
+    if text.to_lowercase().contains("error") {
+        Severity::Error
+    } else {
+        Severity::Warning
+    }
+}
+
+/// Parse one log line into a diagnostic. `log_index` is the line's position in the log.
+pub fn parse_line(line: &str, log_index: usize) -> Option<Diagnostic> {
+    if let Some(caps) = msvc_regex().captures(line) {
+        return Some(Diagnostic {
+            severity: severity(&caps[4]),
+            source: DiagnosticSource::Msvc,
+            file: Some(caps[1].trim().to_string()),
+            line: caps[2].parse().ok(),
+            column: caps.get(3).and_then(|m| m.as_str().parse().ok()),
+            code: Some(caps[5].to_string()),
+            message: caps[6].trim().to_string(),
+            log_index,
+        });
+    }
+    if let Some(caps) = clang_regex().captures(line) {
+        return Some(Diagnostic {
+            severity: severity(&caps[4]),
+            source: DiagnosticSource::Clang,
+            file: Some(caps[1].trim().to_string()),
+            line: caps[2].parse().ok(),
+            column: caps[3].parse().ok(),
+            code: None,
+            message: caps[5].trim().to_string(),
+            log_index,
+        });
+    }
+    if let Some(caps) = uht_regex().captures(line) {
+        return Some(Diagnostic {
+            severity: severity(&caps[3]),
+            source: DiagnosticSource::Uht,
+            file: Some(caps[1].trim().to_string()),
+            line: caps[2].parse().ok(),
+            column: None,
+            code: None,
+            message: caps[4].trim().to_string(),
+            log_index,
+        });
+    }
+    if let Some(caps) = ubt_regex().captures(line) {
+        let level = caps.get(1).or_else(|| caps.get(2))?.as_str();
+        return Some(Diagnostic {
+            severity: severity(level),
+            source: DiagnosticSource::Ubt,
+            file: None,
+            line: None,
+            column: None,
+            code: None,
+            message: caps[3].trim().to_string(),
+            log_index,
+        });
+    }
+    None
+}
+
+/// Whether `diagnostic` was already reported by the job whose log starts at
+/// `job_start`. Unity builds and UBT retries often repeat the same diagnostic; only the
+/// current job counts, so a queued job hitting an earlier job's error still reports it.
+pub fn is_duplicate(diagnostics: &[Diagnostic], job_start: usize, diagnostic: &Diagnostic) -> bool {
+    diagnostics.iter().any(|d| {
+        d.log_index >= job_start
+            && d.file == diagnostic.file
+            && d.line == diagnostic.line
+            && d.column == diagnostic.column
+            && d.message == diagnostic.message
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_msvc_errors() {
+        let d = parse_line(
+            r"C:\Proj\Source\Foo.cpp(42,7): error C2065: 'x': undeclared identifier",
+            3,
+        )
+        .unwrap();
+        assert_eq!(d.source, DiagnosticSource::Msvc);
+        assert_eq!(d.severity, Severity::Error);
+        assert_eq!(d.file.as_deref(), Some(r"C:\Proj\Source\Foo.cpp"));
+        assert_eq!((d.line, d.column), (Some(42), Some(7)));
+        assert_eq!(d.code.as_deref(), Some("C2065"));
+        assert_eq!(d.message, "'x': undeclared identifier");
+        assert_eq!(d.log_index, 3);
+
+        let d = parse_line(
+            r"C:\Proj\Source\Foo.cpp(1): fatal error C1083: Cannot open include file",
+            0,
+        )
+        .unwrap();
+        assert_eq!(d.severity, Severity::Error);
+        assert_eq!(d.column, None);
+    }
+
+    #[test]
+    fn parses_clang_warnings() {
+        let d = parse_line(
+            "/proj/Source/Foo.cpp:42:7: warning: unused variable 'x' [-Wunused-variable]",
+            0,
+        )
+        .unwrap();
+        assert_eq!(d.source, DiagnosticSource::Clang);
+        assert_eq!(d.severity, Severity::Warning);
+        assert_eq!(d.file.as_deref(), Some("/proj/Source/Foo.cpp"));
+        assert_eq!((d.line, d.column), (Some(42), Some(7)));
+        assert_eq!(d.message, "unused variable 'x' [-Wunused-variable]");
+    }
+
+    #[test]
+    fn parses_header_tool_errors() {
+        let d = parse_line(
+            r"C:\Proj\Source\Foo.h(12): Error: Unrecognized type 'FBar'",
+            0,
+        )
+        .unwrap();
+        assert_eq!(d.source, DiagnosticSource::Uht);
+        assert_eq!(d.severity, Severity::Error);
+        assert_eq!(d.line, Some(12));
+        assert_eq!(d.message, "Unrecognized type 'FBar'");
+    }
+
+    #[test]
+    fn parses_build_tool_messages() {
+        let d = parse_line("ERROR: Could not find definition for module 'Foo'", 0).unwrap();
+        assert_eq!(d.source, DiagnosticSource::Ubt);
+        assert_eq!(d.severity, Severity::Error);
+        assert_eq!(d.file, None);
+        assert_eq!(d.message, "Could not find definition for module 'Foo'");
+
+        let d = parse_line("UnrealBuildTool : warning : Plugin 'Bar' is deprecated", 0).unwrap();
+        assert_eq!(d.severity, Severity::Warning);
+        assert_eq!(d.message, "Plugin 'Bar' is deprecated");
+    }
+
+    #[test]
+    fn duplicates_count_within_the_current_job_only() {
+        let line = r"C:\Proj\Source\Foo.cpp(42): error C2065: 'x': undeclared identifier";
+        let first = parse_line(line, 5).unwrap();
+        let again = parse_line(line, 9).unwrap();
+        let diagnostics = [first];
+        assert!(is_duplicate(&diagnostics, 0, &again));
+        assert!(is_duplicate(&diagnostics, 5, &again));
+        // The next queued job started after the first report.
+        assert!(!is_duplicate(&diagnostics, 6, &again));
+
+        let other = parse_line(r"C:\Proj\Source\Foo.cpp(43): error C2065: 'y'", 9).unwrap();
+        assert!(!is_duplicate(&diagnostics, 0, &other));
+    }
+
+    #[test]
+    fn ignores_ordinary_lines() {
+        assert_eq!(parse_line("[12/40] Compile Foo.cpp", 0), None);
+        assert_eq!(parse_line("Total execution time: 3.21 seconds", 0), None);
+    }
+}
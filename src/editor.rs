@@ -0,0 +1,208 @@
+use std::path::Path;
+
+/// A resolved "open file at line" command.
+pub struct EditorCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Runs inside the terminal (vim, nano, ...), so the TUI has to step aside while it runs.
+    pub terminal: bool,
+}
+
+/// Editors that take over the terminal instead of opening a window.
+const TERMINAL_EDITORS: &[&str] = &[
+    "vi", "vim", "nvim", "nano", "emacs", "micro", "hx", "helix", "kak", "joe",
+];
+
+fn program_name(program: &str) -> String {
+    Path::new(program)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+/// Command template used when none is configured, derived from `$VISUAL` / `$EDITOR`.
+/// Falls back to VS Code.
+pub fn default_template() -> String {
+    let editor = std::env::var("VISUAL")
+        .ok()
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|e| !e.trim().is_empty());
+    let Some(editor) = editor else {
+        return "code -g {file}:{line}:{column}".to_string();
+    };
+    let program = split_command_line(&editor)
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    match program_name(&program).as_str() {
+        "code" | "code-insiders" | "codium" | "cursor" => {
+            format!("{} -g {{file}}:{{line}}:{{column}}", editor)
+        }
+        "subl" | "zed" => format!("{} {{file}}:{{line}}:{{column}}", editor),
+        "rider" | "rider64" | "idea" | "idea64" | "clion" | "clion64" => {
+            format!("{} --line {{line}} {{file}}", editor)
+        }
+        name if TERMINAL_EDITORS.contains(&name) => format!("{} +{{line}} {{file}}", editor),
+        _ => format!("{} {{file}}", editor),
+    }
+}
+
+/// Fill `{file}`, `{line}` and `{column}` into a command template and split it into
+/// program and arguments. Placeholders are substituted per argument, so paths with
+/// spaces stay a single argument.
+pub fn build_command(
+    template: &str,
+    file: &str,
+    line: u32,
+    column: u32,
+) -> Result<EditorCommand, String> {
+    let mut parts = split_command_line(template).into_iter().map(|part| {
+        part.replace("{file}", file)
+            .replace("{line}", &line.to_string())
+            .replace("{column}", &column.to_string())
+    });
+    let program = parts
+        .next()
+        .ok_or_else(|| "The editor command is empty.".to_string())?;
+    let args: Vec<String> = parts.collect();
+    let terminal = TERMINAL_EDITORS.contains(&program_name(&program).as_str());
+    Ok(EditorCommand {
+        program: resolve_program(program),
+        args,
+        terminal,
+    })
+}
+
+/// On Windows, find a bare program name through `PATH` and `PATHEXT`, since spawning
+/// does not try extensions other than `.exe` and editors such as VS Code install a
+/// `code.cmd` launcher. Elsewhere (or if nothing is found) the name is kept as is.
+fn resolve_program(program: String) -> String {
+    if !cfg!(windows) || Path::new(&program).extension().is_some() {
+        return program;
+    }
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into());
+    let found = std::env::split_paths(&path).find_map(|dir| {
+        extensions
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| dir.join(format!("{}{}", program, ext.to_lowercase())))
+            .find(|candidate| candidate.is_file())
+    });
+    found
+        .map(|found| found.to_string_lossy().to_string())
+        .unwrap_or(program)
+}
+
+/// Split a command line on whitespace, keeping double- or single-quoted parts together.
+fn split_command_line(input: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+    let mut in_part = false;
+    for c in input.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_part = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_part {
+                    parts.push(std::mem::take(&mut current));
+                    in_part = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_part = true;
+            }
+        }
+    }
+    if in_part {
+        parts.push(current);
+    }
+    parts
+}
+
+/// Extensions of files worth opening even when the log names them without a directory.
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "cpp", "cc", "c", "h", "hpp", "inl", "cs", "ini", "uproject", "uplugin", "usf", "ush",
+];
+
+/// Find a `file(line)` / `file:line[:col]` location in an arbitrary log line. The file
+/// must have a directory or a source extension, so UE log timestamps such as
+/// `[2024.05.01-10.22.33:123]` are not taken for locations.
+pub fn find_location(line: &str) -> Option<(String, u32, u32)> {
+    static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = RE.get_or_init(|| {
+        regex::Regex::new(
+            r"((?:[A-Za-z]:)?[^\s:()\[\]]+\.[A-Za-z0-9]+)(?:\((\d+)(?:,(\d+))?\)|:(\d+)(?::(\d+))?)",
+        )
+        .unwrap()
+    });
+    re.captures_iter(line).find_map(|caps| {
+        let file = caps[1].to_string();
+        let extension = Path::new(&file)
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if !file.contains(['/', '\\']) && !SOURCE_EXTENSIONS.contains(&extension.as_str()) {
+            return None;
+        }
+        let line_no = caps
+            .get(2)
+            .or_else(|| caps.get(4))
+            .and_then(|m| m.as_str().parse().ok())?;
+        let column = caps
+            .get(3)
+            .or_else(|| caps.get(5))
+            .and_then(|m| m.as_str().parse().ok())
+            .unwrap_or(1);
+        Some((file, line_no, column))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_quoted_arguments() {
+        assert_eq!(
+            split_command_line(r#"code -g "{file}:{line}"  'a b' c"#),
+            ["code", "-g", "{file}:{line}", "a b", "c"]
+        );
+        assert_eq!(split_command_line(r#"vim """#), ["vim", ""]);
+        assert!(split_command_line("   ").is_empty());
+    }
+
+    #[test]
+    fn finds_compiler_locations() {
+        assert_eq!(
+            find_location(r"C:\Proj\Source\Foo.cpp(42,7): error C2065"),
+            Some((r"C:\Proj\Source\Foo.cpp".to_string(), 42, 7))
+        );
+        assert_eq!(
+            find_location("In file included from /proj/Source/Foo.h:12:"),
+            Some(("/proj/Source/Foo.h".to_string(), 12, 1))
+        );
+        assert_eq!(
+            find_location("Foo.cpp:3:9: warning: unused"),
+            Some(("Foo.cpp".to_string(), 3, 9))
+        );
+    }
+
+    #[test]
+    fn ignores_log_timestamps() {
+        assert_eq!(
+            find_location("[2024.05.01-10.22.33:123][  0]LogInit: Display: Starting"),
+            None
+        );
+        assert_eq!(
+            find_location("[2024.05.01-10.22.33:123][  0]LogCompile: Error: Foo.cpp(8): oops"),
+            Some(("Foo.cpp".to_string(), 8, 1))
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::types::EngineInstall;
 use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Scan for Unreal Engine installations (ported from the Tauri backend).
 pub fn detect_engines() -> Vec<EngineInstall> {
@@ -142,6 +142,23 @@ pub fn detect_engines() -> Vec<EngineInstall> {
     installs
 }
 
+/// Read the engine version (e.g. "5.4.2") from `Engine/Build/Build.version`.
+pub fn read_engine_version(engine_path: &str) -> Option<String> {
+    let path = Path::new(engine_path)
+        .join("Engine")
+        .join("Build")
+        .join("Build.version");
+    let contents = fs::read_to_string(path).ok()?;
+    let data: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let part = |key: &str| data.get(key).and_then(|v| v.as_u64());
+    Some(format!(
+        "{}.{}.{}",
+        part("MajorVersion")?,
+        part("MinorVersion")?,
+        part("PatchVersion").unwrap_or(0)
+    ))
+}
+
 fn parse_version_from_name(name: &str) -> Option<String> {
     if let Some(caps) = regex::Regex::new(r"(?i)UE[_-]([0-9]+(?:\.[0-9]+)*)")
         .ok()
@@ -171,7 +188,7 @@ fn should_skip_directory(name: &str) -> bool {
     skip.iter().any(|s| lower == *s)
 }
 
-fn is_engine_root(path: &PathBuf) -> bool {
+fn is_engine_root(path: &Path) -> bool {
     let engine_dir = path.join("Engine");
     if !engine_dir.is_dir() {
         return false;
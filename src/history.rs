@@ -0,0 +1,125 @@
+use crate::config::config_path;
+use crate::types::{BuildRecord, LogLine};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+/// Oldest records (and their logs) are dropped beyond this many entries.
+const MAX_RECORDS: usize = 200;
+
+/// Directory holding `config.json`, the history file and saved build logs.
+fn data_dir() -> PathBuf {
+    config_path()
+        .parent()
+        .map(|p| p.to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// `history.json` next to `config.json`.
+pub fn history_path() -> PathBuf {
+    data_dir().join("history.json")
+}
+
+fn logs_dir() -> PathBuf {
+    data_dir().join("logs")
+}
+
+/// Load recorded builds, oldest first. Returns an empty history if the file is missing.
+pub fn load_history() -> Vec<BuildRecord> {
+    match fs::read_to_string(history_path()) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn save_history(records: &[BuildRecord]) -> Result<(), String> {
+    let path = history_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let json = serde_json::to_string_pretty(records)
+        .map_err(|e| format!("Failed to serialize history: {}", e))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to write history: {}", e))
+}
+
+/// Write a build's log to the logs directory and return the file path. `dropped` is
+/// the number of earlier lines of the build no longer in `lines`.
+fn save_log(record: &BuildRecord, lines: &[LogLine], dropped: usize) -> Result<String, String> {
+    use chrono::TimeZone;
+    let dir = logs_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create logs dir: {}", e))?;
+    // Local time, like the history panel.
+    let stamp = chrono::Local
+        .timestamp_opt(record.started_at, 0)
+        .single()
+        .map(|t| t.format("%Y%m%d-%H%M%S").to_string())
+        .unwrap_or_else(|| record.started_at.to_string());
+    let name: String = record
+        .project_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    let mut text = String::new();
+    if dropped > 0 {
+        text.push_str(&format!(
+            "[{} earlier lines of this build were dropped from the in-app log and are missing here]\n",
+            dropped
+        ));
+    }
+    text.extend(lines.iter().map(|l| format!("{}\n", l.text)));
+
+    // Queued builds of one project can start within the same second; never overwrite
+    // another record's log.
+    let mut path = dir.join(format!("{}-{}.log", stamp, name));
+    let mut suffix = 1;
+    loop {
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(text.as_bytes())
+                    .map_err(|e| format!("Failed to write build log: {}", e))?;
+                return Ok(path.to_string_lossy().to_string());
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                suffix += 1;
+                path = dir.join(format!("{}-{}-{}.log", stamp, name, suffix));
+            }
+            Err(e) => return Err(format!("Failed to write build log: {}", e)),
+        }
+    }
+}
+
+/// Read a saved build log back, one entry per line.
+pub fn load_log(path: &str) -> Result<Vec<String>, String> {
+    fs::read_to_string(path)
+        .map(|text| text.lines().map(String::from).collect())
+        .map_err(|e| format!("Failed to read {}: {}", path, e))
+}
+
+/// Save the build's log, append the record and persist the history,
+/// pruning the oldest records and their logs beyond `MAX_RECORDS`.
+pub fn record_build(
+    history: &mut Vec<BuildRecord>,
+    mut record: BuildRecord,
+    lines: &[LogLine],
+    dropped: usize,
+) -> Result<(), String> {
+    let log_result = save_log(&record, lines, dropped);
+    record.log_file = log_result.as_ref().ok().cloned();
+    history.push(record);
+
+    if history.len() > MAX_RECORDS {
+        let excess = history.len() - MAX_RECORDS;
+        for old in history.drain(0..excess) {
+            if let Some(log_file) = old.log_file {
+                let _ = fs::remove_file(log_file);
+            }
+        }
+    }
+
+    save_history(history)?;
+    log_result.map(|_| ())
+}
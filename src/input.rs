@@ -25,6 +25,10 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
             app.open_help();
             return;
         }
+        KeyCode::F(8) => {
+            app.jump_to_problem(!key.modifiers.contains(KeyModifiers::SHIFT));
+            return;
+        }
         // Arrow keys: panel navigation, with build-button horizontal navigation.
         KeyCode::Right | KeyCode::Tab => {
             if matches!(app.focus, FocusItem::BuildButton(_)) {
@@ -42,13 +46,27 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
             }
             return;
         }
+        KeyCode::Down if app.focus == FocusItem::Problems => {
+            let row = app.problem_selected.map_or(0, |r| r + 1);
+            if row < app.diagnostics.len() {
+                app.select_problem(row);
+            }
+            return;
+        }
+        KeyCode::Up if app.focus == FocusItem::Problems => {
+            match app.problem_selected {
+                Some(0) | None => app.focus_prev(),
+                Some(row) => app.select_problem(row - 1),
+            }
+            return;
+        }
         KeyCode::Down => {
             if app.focus == FocusItem::Logs {
                 if app.auto_scroll_logs {
                     app.follow_latest_logs();
                 } else {
                     app.log_scroll = app.log_scroll.saturating_add(1);
-                    if app.log_scroll >= app.logs.len().saturating_sub(1) {
+                    if app.log_scroll >= app.visible_logs().len().saturating_sub(1) {
                         app.follow_latest_logs();
                     }
                 }
@@ -60,7 +78,7 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
         KeyCode::Up => {
             if app.focus == FocusItem::Logs {
                 if app.auto_scroll_logs {
-                    app.log_scroll = app.logs.len().saturating_sub(2);
+                    app.log_scroll = app.visible_logs().len().saturating_sub(2);
                 } else {
                     app.log_scroll = app.log_scroll.saturating_sub(1);
                 }
@@ -80,6 +98,7 @@ pub fn handle_key(app: &mut App, key: KeyEvent) {
         FocusItem::Engine => handle_engine_key(app, key),
         FocusItem::BuildButton(idx) => handle_build_button_key(app, key, *idx),
         FocusItem::Logs => handle_logs_key(app, key),
+        FocusItem::Problems => handle_problems_key(app, key),
     }
 }
 
@@ -144,24 +163,75 @@ fn handle_build_button_key(app: &mut App, key: KeyEvent, index: usize) {
         }
         // Shortcut keys still work
         KeyCode::Char('b') => {
-            if app.build_state != BuildState::Running {
-                app.start_build();
-            }
+            app.start_build();
         }
         KeyCode::Char('n') => {
-            if app.build_state != BuildState::Running {
-                app.start_clean_rebuild();
-            }
+            app.start_clean_rebuild();
+        }
+        KeyCode::Char('k') => {
+            app.start_package();
+        }
+        KeyCode::Char('o') => {
+            app.open_package_options();
+        }
+        KeyCode::Char('F') => {
+            app.open_project_files_picker();
+        }
+        KeyCode::Char('u') => {
+            app.open_build_queue();
         }
         KeyCode::Char('c') => {
             app.cancel_build();
         }
-        KeyCode::Char('x') => {
-            if app.build_state != BuildState::Running {
-                app.clear_logs();
-                app.build_state = BuildState::Idle;
-                app.focus = FocusItem::BuildButton(0);
-            }
+        KeyCode::Char('p') if app.build_state != BuildState::Running => {
+            app.cycle_platform();
+        }
+        KeyCode::Char('g') if app.build_state != BuildState::Running => {
+            app.cycle_configuration();
+        }
+        KeyCode::Char('t') if app.build_state != BuildState::Running => {
+            app.open_target_picker();
+        }
+        KeyCode::Char('s') if app.build_state != BuildState::Running => {
+            app.cycle_preset();
+        }
+        KeyCode::Char('S') => {
+            app.open_presets();
+        }
+        KeyCode::Char('a') => {
+            app.open_extra_args_dialog();
+        }
+        KeyCode::Char('v') => {
+            app.open_env_dialog();
+        }
+        KeyCode::Char('h') => {
+            app.open_hooks();
+        }
+        KeyCode::Char('e') => {
+            app.launch_selected(LaunchMode::Editor);
+        }
+        KeyCode::Char('G') => {
+            app.launch_selected(LaunchMode::Game);
+        }
+        KeyCode::Char('L') => {
+            app.cycle_auto_launch();
+        }
+        KeyCode::Char('l') => {
+            app.open_launch_profiles();
+        }
+        KeyCode::Char('H') => {
+            app.open_history();
+        }
+        KeyCode::Char('C') => {
+            app.open_clean_dialog();
+        }
+        KeyCode::Char('X') => {
+            app.stop_launch_session();
+        }
+        KeyCode::Char('x') if app.build_state != BuildState::Running => {
+            app.clear_logs();
+            app.build_state = BuildState::Idle;
+            app.focus = FocusItem::BuildButton(0);
         }
         KeyCode::Char('y') => {
             app.copy_logs();
@@ -185,7 +255,7 @@ fn handle_logs_key(app: &mut App, key: KeyEvent) {
             app.auto_scroll_logs = false;
         }
         KeyCode::Char('G') => {
-            app.log_scroll = app.logs.len().saturating_sub(1);
+            app.log_scroll = app.visible_logs().len().saturating_sub(1);
             app.auto_scroll_logs = true;
         }
         KeyCode::PageDown => {
@@ -196,15 +266,50 @@ fn handle_logs_key(app: &mut App, key: KeyEvent) {
             app.log_scroll = app.log_scroll.saturating_sub(20);
             app.auto_scroll_logs = false;
         }
-        KeyCode::Char('x') => {
-            if app.build_state != BuildState::Running {
-                app.clear_logs();
-                app.build_state = BuildState::Idle;
-            }
+        KeyCode::Char('x') if app.build_state != BuildState::Running => {
+            app.clear_logs();
+            app.build_state = BuildState::Idle;
         }
         KeyCode::Char('y') => {
             app.copy_logs();
         }
+        KeyCode::Char(']') => {
+            app.cycle_log_tab(true);
+        }
+        KeyCode::Char('o') => {
+            app.open_log_location();
+        }
+        KeyCode::Char('O') => {
+            app.open_editor_command_dialog();
+        }
+        KeyCode::Char('[') => {
+            app.cycle_log_tab(false);
+        }
+        _ => {}
+    }
+}
+
+fn handle_problems_key(app: &mut App, key: KeyEvent) {
+    let len = app.diagnostics.len();
+    match key.code {
+        KeyCode::Char('j') if len > 0 => {
+            let row = app.problem_selected.map_or(0, |r| (r + 1).min(len - 1));
+            app.select_problem(row);
+        }
+        KeyCode::Char('k') if len > 0 => {
+            let row = app.problem_selected.map_or(0, |r| r.saturating_sub(1));
+            app.select_problem(row);
+        }
+        KeyCode::Enter => {
+            if let Some(row) = app.problem_selected {
+                app.select_problem(row);
+                app.focus = FocusItem::Logs;
+            }
+        }
+        KeyCode::Char('n') => app.jump_to_problem(true),
+        KeyCode::Char('N') => app.jump_to_problem(false),
+        KeyCode::Char('o') => app.open_selected_problem(),
+        KeyCode::Char('O') => app.open_editor_command_dialog(),
         _ => {}
     }
 }
@@ -214,6 +319,18 @@ fn handle_dialog_key(app: &mut App, key: KeyEvent) {
         Some(DialogKind::PathInput { .. }) => handle_path_input_key(app, key),
         Some(DialogKind::EnginePicker) => handle_engine_picker_key(app, key),
         Some(DialogKind::EditorTargetPicker { .. }) => handle_editor_target_picker_key(app, key),
+        Some(DialogKind::TargetPicker { .. }) => handle_target_picker_key(app, key),
+        Some(DialogKind::ProjectFilesPicker { .. }) => handle_project_files_picker_key(app, key),
+        Some(DialogKind::PackageOptions { .. }) => handle_package_options_key(app, key),
+        Some(DialogKind::BuildQueue { .. }) => handle_build_queue_key(app, key),
+        Some(DialogKind::Presets { .. }) => handle_presets_key(app, key),
+        Some(DialogKind::PresetEditor { .. }) => handle_preset_editor_key(app, key),
+        Some(DialogKind::Hooks { .. }) => handle_hooks_key(app, key),
+        Some(DialogKind::History { .. }) => handle_history_key(app, key),
+        Some(DialogKind::Clean { .. }) => handle_clean_key(app, key),
+        Some(DialogKind::BuildSummary) => handle_build_summary_key(app, key),
+        Some(DialogKind::LaunchProfiles { .. }) => handle_launch_profiles_key(app, key),
+        Some(DialogKind::LaunchProfileEditor { .. }) => handle_launch_profile_editor_key(app, key),
         Some(DialogKind::Confirm { .. }) => handle_confirm_key(app, key),
         Some(DialogKind::Help) => {
             app.close_dialog();
@@ -316,6 +433,424 @@ fn handle_editor_target_picker_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+fn handle_project_files_picker_key(app: &mut App, key: KeyEvent) {
+    let len = ProjectFileFormat::ALL.len() + 1;
+    match key.code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter => app.confirm_dialog(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            if let Some(DialogKind::ProjectFilesPicker { selected, .. }) = &mut app.dialog {
+                *selected = (*selected + 1) % len;
+            }
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            if let Some(DialogKind::ProjectFilesPicker { selected, .. }) = &mut app.dialog {
+                *selected = (*selected + len - 1) % len;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_target_picker_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter => app.confirm_dialog(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            if let Some(DialogKind::TargetPicker {
+                targets, selected, ..
+            }) = &mut app.dialog
+            {
+                let len = targets.len() + 1;
+                *selected = (*selected + 1) % len;
+            }
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            if let Some(DialogKind::TargetPicker {
+                targets, selected, ..
+            }) = &mut app.dialog
+            {
+                let len = targets.len() + 1;
+                *selected = (*selected + len - 1) % len;
+            }
+        }
+        KeyCode::Char('m') => {
+            if let Some(DialogKind::TargetPicker { project_index, .. }) = &app.dialog {
+                let value = app
+                    .config
+                    .projects
+                    .get(*project_index)
+                    .and_then(|p| p.target.clone())
+                    .unwrap_or_default();
+                app.dialog = Some(DialogKind::PathInput {
+                    label: "Set Build Target (e.g. MyGameServer)".into(),
+                    value,
+                    target: PathInputTarget::SetBuildTarget(*project_index),
+                });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_package_options_key(app: &mut App, key: KeyEvent) {
+    const ROWS: usize = 5;
+    match key.code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter | KeyCode::Char(' ') => app.activate_package_option(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            if let Some(DialogKind::PackageOptions { selected, .. }) = &mut app.dialog {
+                *selected = (*selected + 1) % ROWS;
+            }
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            if let Some(DialogKind::PackageOptions { selected, .. }) = &mut app.dialog {
+                *selected = (*selected + ROWS - 1) % ROWS;
+            }
+        }
+        _ => {}
+    }
+}
+
+fn handle_presets_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::Presets {
+        project_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let len = app
+        .config
+        .projects
+        .get(project_index)
+        .map(|p| p.presets.len())
+        .unwrap_or(0);
+    match key.code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter => app.confirm_dialog(),
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => {
+            app.dialog = Some(DialogKind::Presets {
+                project_index,
+                selected: (selected + 1) % len,
+            });
+        }
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => {
+            app.dialog = Some(DialogKind::Presets {
+                project_index,
+                selected: (selected + len - 1) % len,
+            });
+        }
+        KeyCode::Char('a') => {
+            app.dialog = Some(DialogKind::PathInput {
+                label: "New Preset Name".into(),
+                value: String::new(),
+                target: PathInputTarget::NewPreset(project_index),
+            });
+        }
+        KeyCode::Char('e') if selected < len => {
+            app.dialog = Some(DialogKind::PresetEditor {
+                project_index,
+                preset_index: selected,
+                selected: 0,
+            });
+        }
+        KeyCode::Char('d') | KeyCode::Delete if selected < len => {
+            let name = app.config.projects[project_index].presets[selected]
+                .name
+                .clone();
+            app.dialog = Some(DialogKind::Confirm {
+                message: format!("Delete preset \"{}\"?", name),
+                action: ConfirmAction::DeletePreset(project_index, selected),
+            });
+        }
+        KeyCode::Char('n') => {
+            if let Some(project) = app.config.projects.get_mut(project_index) {
+                project.active_preset = None;
+                app.save_config();
+            }
+            app.close_dialog();
+        }
+        _ => {}
+    }
+}
+
+fn handle_preset_editor_key(app: &mut App, key: KeyEvent) {
+    const ROWS: usize = 8;
+    let Some(DialogKind::PresetEditor {
+        project_index,
+        preset_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let selected = match key.code {
+        KeyCode::Esc => {
+            app.dialog = Some(DialogKind::Presets {
+                project_index,
+                selected: preset_index,
+            });
+            return;
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            app.activate_preset_field();
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down => (selected + 1) % ROWS,
+        KeyCode::Char('k') | KeyCode::Up => (selected + ROWS - 1) % ROWS,
+        _ => return,
+    };
+    app.dialog = Some(DialogKind::PresetEditor {
+        project_index,
+        preset_index,
+        selected,
+    });
+}
+
+fn handle_build_summary_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.close_dialog(),
+        KeyCode::Enter | KeyCode::Char('p') => app.confirm_dialog(),
+        KeyCode::Char('r') => app.rebuild_from_summary(),
+        KeyCode::Char('c') => app.copy_errors(),
+        _ => {}
+    }
+}
+
+fn handle_history_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::History { selected }) = app.dialog.clone() else {
+        return;
+    };
+    let len = app.history.len();
+    let selected = match key.code {
+        KeyCode::Esc => {
+            app.close_dialog();
+            return;
+        }
+        KeyCode::Enter => {
+            app.confirm_dialog();
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => (selected + 1) % len,
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => (selected + len - 1) % len,
+        KeyCode::PageDown => (selected + 10).min(len.saturating_sub(1)),
+        KeyCode::PageUp => selected.saturating_sub(10),
+        _ => return,
+    };
+    app.dialog = Some(DialogKind::History { selected });
+}
+
+fn handle_launch_profiles_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::LaunchProfiles {
+        project_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let len = app
+        .config
+        .projects
+        .get(project_index)
+        .map(|p| p.launch_profiles.len())
+        .unwrap_or(0);
+    match key.code {
+        KeyCode::Esc => app.close_dialog(),
+        KeyCode::Enter if selected < len => app.confirm_dialog(),
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => {
+            app.dialog = Some(DialogKind::LaunchProfiles {
+                project_index,
+                selected: (selected + 1) % len,
+            });
+        }
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => {
+            app.dialog = Some(DialogKind::LaunchProfiles {
+                project_index,
+                selected: (selected + len - 1) % len,
+            });
+        }
+        KeyCode::Char('a') => {
+            app.dialog = Some(DialogKind::PathInput {
+                label: "New Launch Profile Name".into(),
+                value: String::new(),
+                target: PathInputTarget::NewLaunchProfile(project_index),
+            });
+        }
+        KeyCode::Char('e') if selected < len => {
+            app.dialog = Some(DialogKind::LaunchProfileEditor {
+                project_index,
+                profile_index: selected,
+                selected: 0,
+            });
+        }
+        KeyCode::Char('d') | KeyCode::Delete if selected < len => {
+            let name = app.config.projects[project_index].launch_profiles[selected]
+                .name
+                .clone();
+            app.dialog = Some(DialogKind::Confirm {
+                message: format!("Delete launch profile \"{}\"?", name),
+                action: ConfirmAction::DeleteLaunchProfile(project_index, selected),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn handle_launch_profile_editor_key(app: &mut App, key: KeyEvent) {
+    const ROWS: usize = 9;
+    let Some(DialogKind::LaunchProfileEditor {
+        project_index,
+        profile_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let selected = match key.code {
+        KeyCode::Esc => {
+            app.dialog = Some(DialogKind::LaunchProfiles {
+                project_index,
+                selected: profile_index,
+            });
+            return;
+        }
+        KeyCode::Enter | KeyCode::Char(' ') => {
+            app.activate_launch_profile_field();
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down => (selected + 1) % ROWS,
+        KeyCode::Char('k') | KeyCode::Up => (selected + ROWS - 1) % ROWS,
+        _ => return,
+    };
+    app.dialog = Some(DialogKind::LaunchProfileEditor {
+        project_index,
+        profile_index,
+        selected,
+    });
+}
+
+fn handle_hooks_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::Hooks {
+        project_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let len = app
+        .config
+        .projects
+        .get(project_index)
+        .map(|p| p.pre_build_hooks.len() + p.post_build_hooks.len())
+        .unwrap_or(0);
+    let new_selected = match key.code {
+        KeyCode::Esc => {
+            app.close_dialog();
+            return;
+        }
+        KeyCode::Char('a') => {
+            app.add_hook(project_index, HookStage::Pre);
+            return;
+        }
+        KeyCode::Char('A') => {
+            app.add_hook(project_index, HookStage::Post);
+            return;
+        }
+        KeyCode::Enter | KeyCode::Char('e') => {
+            app.edit_hook(project_index, selected);
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => (selected + 1) % len,
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => (selected + len - 1) % len,
+        KeyCode::Char('K') => app.move_hook(project_index, selected, true),
+        KeyCode::Char('J') => app.move_hook(project_index, selected, false),
+        KeyCode::Char('d') | KeyCode::Delete => {
+            app.remove_hook(project_index, selected);
+            selected.min(len.saturating_sub(2))
+        }
+        _ => selected,
+    };
+    app.dialog = Some(DialogKind::Hooks {
+        project_index,
+        selected: new_selected,
+    });
+}
+
+fn handle_clean_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::Clean {
+        project_index,
+        selected,
+    }) = app.dialog.clone()
+    else {
+        return;
+    };
+    let len = app.clean_candidates.len();
+    let new_selected = match key.code {
+        KeyCode::Esc => {
+            app.close_dialog();
+            return;
+        }
+        KeyCode::Char('d') => {
+            app.clean_dry_run(project_index);
+            selected
+        }
+        KeyCode::Char('b') => {
+            app.toggle_clean_backup(project_index);
+            selected
+        }
+        KeyCode::Char('u') => {
+            app.restore_last_clean(project_index);
+            selected
+        }
+        KeyCode::Char('r') => {
+            app.close_dialog();
+            app.start_clean_rebuild();
+            return;
+        }
+        KeyCode::Char(' ') | KeyCode::Enter => {
+            app.toggle_clean_dir(project_index, selected);
+            selected
+        }
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => (selected + 1) % len,
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => (selected + len - 1) % len,
+        _ => selected,
+    };
+    app.dialog = Some(DialogKind::Clean {
+        project_index,
+        selected: new_selected,
+    });
+}
+
+fn handle_build_queue_key(app: &mut App, key: KeyEvent) {
+    let Some(DialogKind::BuildQueue { selected }) = app.dialog.clone() else {
+        return;
+    };
+    let len = app.queue.len();
+    let new_selected = match key.code {
+        KeyCode::Esc | KeyCode::Enter => {
+            app.close_dialog();
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down if len > 0 => (selected + 1) % len,
+        KeyCode::Char('k') | KeyCode::Up if len > 0 => (selected + len - 1) % len,
+        KeyCode::Char('K') => app.move_queued_job(selected, true),
+        KeyCode::Char('J') => app.move_queued_job(selected, false),
+        KeyCode::Char('d') | KeyCode::Delete => {
+            app.remove_queued_job(selected);
+            selected
+        }
+        KeyCode::Char('x') => {
+            app.clear_finished_jobs();
+            0
+        }
+        _ => selected,
+    };
+    app.dialog = Some(DialogKind::BuildQueue {
+        selected: new_selected.min(app.queue.len().saturating_sub(1)),
+    });
+}
+
 fn handle_confirm_key(app: &mut App, key: KeyEvent) {
     match key.code {
         KeyCode::Char('y') | KeyCode::Enter => app.confirm_dialog(),
@@ -0,0 +1,294 @@
+use crate::build::CancelToken;
+use crate::types::{LaunchMode, LaunchProfile, LogLine, ServerMode, TargetPlatform};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// One server or client process of a running launch profile.
+pub struct SessionProcess {
+    /// Tab label, e.g. "Server" or "Client 2".
+    pub label: String,
+    pub logs: Vec<LogLine>,
+    pub rx: mpsc::UnboundedReceiver<String>,
+    finished: Arc<AtomicBool>,
+    cancel: CancelToken,
+    /// Task running the process, until `LaunchSession::stopped` waits for it.
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl SessionProcess {
+    pub fn is_running(&self) -> bool {
+        !self.finished.load(Ordering::Relaxed)
+    }
+}
+
+/// The processes started from one launch profile, stopped together.
+pub struct LaunchSession {
+    pub profile_name: String,
+    pub processes: Vec<SessionProcess>,
+}
+
+impl LaunchSession {
+    /// Number of processes that have not exited yet.
+    pub fn running_count(&self) -> usize {
+        self.processes.iter().filter(|p| p.is_running()).count()
+    }
+
+    /// Signal every process of the session to be killed.
+    pub fn stop_all(&self) {
+        for process in &self.processes {
+            process.cancel.cancel();
+        }
+    }
+
+    /// Resolves once every process of the session (and its tree) has stopped.
+    pub async fn stopped(&mut self) {
+        for process in &mut self.processes {
+            if let Some(task) = process.task.take() {
+                let _ = task.await;
+            }
+        }
+    }
+
+    /// Pids of the session's processes that are still running.
+    pub fn running_pids(&self) -> Vec<u32> {
+        self.processes
+            .iter()
+            .filter_map(|p| p.cancel.process())
+            .collect()
+    }
+}
+
+/// Locate the editor binary of an engine install: `UnrealEditor` on UE5, `UE4Editor` on UE4.
+pub fn find_editor_executable(engine_path: &str) -> Option<PathBuf> {
+    let binaries = Path::new(engine_path)
+        .join("Engine")
+        .join("Binaries")
+        .join(TargetPlatform::host().ubt_name());
+
+    ["UnrealEditor", "UE4Editor"]
+        .iter()
+        .map(|name| {
+            if cfg!(windows) {
+                binaries.join(format!("{}.exe", name))
+            } else if cfg!(target_os = "macos") {
+                binaries
+                    .join(format!("{}.app", name))
+                    .join("Contents")
+                    .join("MacOS")
+                    .join(name)
+            } else {
+                binaries.join(name)
+            }
+        })
+        .find(|path| path.is_file())
+}
+
+/// Start the editor (or a `-game` standalone instance) for a project, detached from Stellar
+/// so it keeps running after the TUI exits. Returns the launched command line for the log.
+pub fn launch_project(
+    engine_path: &str,
+    project_path: &str,
+    mode: LaunchMode,
+) -> Result<String, String> {
+    let editor = find_editor_executable(engine_path).ok_or_else(|| {
+        format!(
+            "UnrealEditor / UE4Editor not found under {}. Is the engine built for this platform?",
+            engine_path
+        )
+    })?;
+
+    let mut cmd = Command::new(&editor);
+    cmd.arg(project_path);
+    if mode == LaunchMode::Game {
+        cmd.arg("-game");
+    }
+    if let Some(dir) = Path::new(project_path).parent() {
+        cmd.current_dir(dir);
+    }
+
+    spawn_detached(&mut cmd)
+        .map_err(|e| format!("Failed to launch {}: {}", editor.display(), e))?;
+
+    let mut display = format!("\"{}\" \"{}\"", editor.display(), project_path);
+    if mode == LaunchMode::Game {
+        display.push_str(" -game");
+    }
+    Ok(display)
+}
+
+/// Spawn a process outside Stellar's console / process group with no inherited stdio.
+pub fn spawn_detached(cmd: &mut Command) -> std::io::Result<()> {
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const DETACHED_PROCESS: u32 = 0x0000_0008;
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+        cmd.creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // A new process group keeps terminal signals (Ctrl+C, hangup) away from the editor.
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd.spawn()?;
+    // Reap the child when it exits so it does not linger as a zombie while Stellar runs.
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+/// The label and editor arguments of every process a profile starts, server first.
+pub fn profile_processes(
+    project_path: &str,
+    profile: &LaunchProfile,
+) -> Result<Vec<(String, Vec<String>)>, String> {
+    let mut common = Vec::new();
+    if profile.log {
+        common.push("-log".to_string());
+    }
+    // Ask the engine to mirror its log to stdout so Stellar can show it per process.
+    common.push("-stdout".to_string());
+    common.push("-FullStdOutLogOutput".to_string());
+
+    let mut window = Vec::new();
+    if profile.windowed {
+        window.push("-windowed".to_string());
+    }
+    if let Some((width, height)) = profile.resolution_size() {
+        window.push(format!("-ResX={}", width));
+        window.push(format!("-ResY={}", height));
+    }
+
+    let mut processes = Vec::new();
+    match profile.server {
+        ServerMode::Dedicated => {
+            let mut args = vec![project_path.to_string()];
+            if !profile.map.is_empty() {
+                args.push(profile.map.clone());
+            }
+            args.push("-server".to_string());
+            args.push(format!("-port={}", profile.port));
+            args.extend(common.iter().cloned());
+            args.extend(profile.extra_args.iter().cloned());
+            processes.push(("Server".to_string(), args));
+        }
+        ServerMode::Listen => {
+            if profile.map.is_empty() {
+                return Err("A listen server needs a map to open.".to_string());
+            }
+            let mut args = vec![
+                project_path.to_string(),
+                format!("{}?listen", profile.map),
+                "-game".to_string(),
+                format!("-port={}", profile.port),
+            ];
+            args.extend(common.iter().cloned());
+            args.extend(window.iter().cloned());
+            args.extend(profile.extra_args.iter().cloned());
+            processes.push(("Server".to_string(), args));
+        }
+        ServerMode::None => {}
+    }
+
+    for i in 1..=profile.clients {
+        let mut args = vec![project_path.to_string()];
+        if profile.server == ServerMode::None {
+            if !profile.map.is_empty() {
+                args.push(profile.map.clone());
+            }
+        } else {
+            args.push(format!("127.0.0.1:{}", profile.port));
+        }
+        args.push("-game".to_string());
+        args.extend(common.iter().cloned());
+        args.extend(window.iter().cloned());
+        args.extend(profile.extra_args.iter().cloned());
+        processes.push((format!("Client {}", i), args));
+    }
+
+    if processes.is_empty() {
+        return Err("The profile starts no server and no clients.".to_string());
+    }
+    Ok(processes)
+}
+
+/// Start every process of a launch profile, each streaming its output to its own log.
+pub fn start_session(
+    engine_path: &str,
+    project_path: &str,
+    profile: &LaunchProfile,
+    cancel_timeout: Duration,
+) -> Result<LaunchSession, String> {
+    let editor = find_editor_executable(engine_path).ok_or_else(|| {
+        format!(
+            "UnrealEditor / UE4Editor not found under {}. Is the engine built for this platform?",
+            engine_path
+        )
+    })?;
+    let project_dir = Path::new(project_path).parent().map(Path::to_path_buf);
+
+    let mut processes = Vec::new();
+    for (label, args) in profile_processes(project_path, profile)? {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let finished = Arc::new(AtomicBool::new(false));
+        let _ = tx.send(format!(
+            "Running: \"{}\" {}",
+            editor.display(),
+            args.join(" ")
+        ));
+
+        let mut cmd = tokio::process::Command::new(&editor);
+        cmd.args(&args);
+        if let Some(dir) = &project_dir {
+            cmd.current_dir(dir);
+        }
+
+        // One token per process, so each one's pid is known when quitting.
+        let cancel = CancelToken::new();
+        let task_cancel = cancel.clone();
+        let finished_clone = finished.clone();
+        let task = tokio::spawn(async move {
+            match crate::build::run_streamed(cmd, &tx, &task_cancel, cancel_timeout).await {
+                Ok(exit) if exit.success() || exit.cancelled => {
+                    let _ = tx.send("Process exited.".to_string());
+                }
+                Ok(exit) => {
+                    let _ = tx.send(match exit.code {
+                        Some(code) => format!("Process exited with code {}.", code),
+                        None => "Process was terminated by a signal.".to_string(),
+                    });
+                }
+                Err(e) => {
+                    let _ = tx.send(e);
+                }
+            }
+            finished_clone.store(true, Ordering::Relaxed);
+        });
+
+        processes.push(SessionProcess {
+            label,
+            logs: Vec::new(),
+            rx,
+            finished,
+            cancel,
+            task: Some(task),
+        });
+    }
+
+    Ok(LaunchSession {
+        profile_name: profile.name.clone(),
+        processes,
+    })
+}
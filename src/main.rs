@@ -1,23 +1,32 @@
 mod app;
+mod backend;
 mod build;
 mod config;
+mod diagnostics;
+mod editor;
 mod engine;
+mod history;
 mod input;
+mod launch;
 mod notify;
+mod process;
 mod types;
 mod ui;
 
 use app::App;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use futures_util::StreamExt;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
 use std::time::{Duration, Instant};
 
 const TICK_RATE: Duration = Duration::from_millis(33); // ~30 fps
+/// Shortest gap between two frames redrawn because of build output.
+const MIN_FRAME: Duration = Duration::from_millis(16);
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -48,36 +57,65 @@ async fn run_app(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut app = App::new();
     let mut last_tick = Instant::now();
+    let mut events = EventStream::new();
 
     loop {
-        // Keep build output draining at high frequency for smooth log updates.
         app.poll_build();
+        app.poll_launch_session();
+        app.poll_clean_sizes();
+        app.poll_restore();
 
         // Render
         terminal.draw(|f| ui::draw(f, &app))?;
+        let drawn = Instant::now();
 
-        // Poll for events with timeout to maintain tick rate
-        let mut timeout = TICK_RATE
+        // Sleep until a key press, build output or completion, or the next tick.
+        let timeout = TICK_RATE
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
-
-        if app.build_state == crate::types::BuildState::Running {
-            timeout = timeout.min(Duration::from_millis(5));
-        }
-
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                // Only handle key press events, ignore release/repeat
-                if key.kind != KeyEventKind::Press {
-                    continue;
+        let mut build_woke = false;
+        tokio::select! {
+            event = events.next() => {
+                if let Some(Event::Key(key)) = event.transpose()? {
+                    // Only handle key press events, ignore release/repeat
+                    if key.kind == KeyEventKind::Press {
+                        // Ctrl+C always quits
+                        if key.modifiers.contains(KeyModifiers::CONTROL)
+                            && key.code == KeyCode::Char('c')
+                        {
+                            app.should_quit = true;
+                        }
+
+                        input::handle_key(&mut app, key);
+                    }
                 }
+            }
+            _ = app.wait_for_build() => build_woke = true,
+            _ = tokio::time::sleep(timeout) => {}
+        }
 
-                // Ctrl+C always quits
-                if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
-                    app.should_quit = true;
-                }
+        // Build output arrives in bursts: let it batch up instead of redrawing per line.
+        if build_woke {
+            if let Some(rest) = MIN_FRAME.checked_sub(drawn.elapsed()) {
+                tokio::time::sleep(rest).await;
+            }
+        }
 
-                input::handle_key(&mut app, key);
+        // Terminal editors (vim, nano, ...) need the screen: suspend the TUI while they run.
+        if let Some(command) = app.pending_terminal_command.take() {
+            // The event stream reads stdin in the background; stop it so the editor gets the keys.
+            drop(events);
+            disable_raw_mode()?;
+            execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+            let status = std::process::Command::new(&command.program)
+                .args(&command.args)
+                .status();
+            enable_raw_mode()?;
+            execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+            terminal.clear()?;
+            events = EventStream::new();
+            if let Err(e) = status {
+                app.push_log(format!("Failed to run {}: {}", command.program, e));
             }
         }
 
@@ -88,8 +126,9 @@ async fn run_app(
         }
 
         if app.should_quit {
-            // Cancel running build before quitting
-            app.cancel_build();
+            // Stop the running build and launched processes before quitting
+            app.stop_build_before_quit().await;
+            app.stop_launch_session_before_quit().await;
             break;
         }
     }
@@ -0,0 +1,171 @@
+use std::process::Stdio;
+use tokio::process::Command;
+
+/// Run the process in its own process group (a new process group on Windows) so the
+/// whole tree it spawns (compilers, linkers, ShaderCompileWorker) can be signalled at once.
+pub fn isolate(cmd: &mut tokio::process::Command) {
+    #[cfg(unix)]
+    {
+        cmd.process_group(0);
+    }
+
+    #[cfg(windows)]
+    {
+        const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+        cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
+    }
+}
+
+/// Processes still alive in the tree rooted at `pid`, as `(pid, name)`.
+#[cfg(unix)]
+pub async fn list_tree(pid: u32) -> Vec<(u32, String)> {
+    let Ok(output) = Command::new("ps")
+        .args(["-A", "-o", "pid=,pgid=,stat=,comm="])
+        .stderr(Stdio::null())
+        .output()
+        .await
+    else {
+        return Vec::new();
+    };
+    // `isolate` made `pid` the group leader, so the group id is the pid. Zombies have
+    // already exited and only wait to be reaped, so they are left out.
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let member: u32 = fields.next()?.parse().ok()?;
+            let group: u32 = fields.next()?.parse().ok()?;
+            let state = fields.next()?;
+            let command = fields.collect::<Vec<_>>().join(" ");
+            if group != pid || state.starts_with('Z') {
+                return None;
+            }
+            let name = std::path::Path::new(&command)
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or(command);
+            Some((member, name))
+        })
+        .collect()
+}
+
+/// Processes still alive in the tree rooted at `pid`, as `(pid, name)`.
+#[cfg(windows)]
+pub async fn list_tree(pid: u32) -> Vec<(u32, String)> {
+    tokio::task::spawn_blocking(move || snapshot_tree(pid))
+        .await
+        .unwrap_or_default()
+}
+
+#[cfg(windows)]
+fn snapshot_tree(pid: u32) -> Vec<(u32, String)> {
+    use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Process32First, Process32Next, PROCESSENTRY32, TH32CS_SNAPPROCESS,
+    };
+
+    let mut processes = Vec::new();
+    unsafe {
+        let snap = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0);
+        if snap == INVALID_HANDLE_VALUE {
+            return Vec::new();
+        }
+
+        let mut entry: PROCESSENTRY32 = std::mem::zeroed();
+        entry.dwSize = std::mem::size_of::<PROCESSENTRY32>() as u32;
+
+        if Process32First(snap, &mut entry) != 0 {
+            loop {
+                let name: Vec<u8> = entry
+                    .szExeFile
+                    .iter()
+                    .take_while(|c| **c != 0)
+                    .map(|c| *c as u8)
+                    .collect();
+                processes.push((
+                    entry.th32ProcessID,
+                    entry.th32ParentProcessID,
+                    String::from_utf8_lossy(&name).to_string(),
+                ));
+                if Process32Next(snap, &mut entry) == 0 {
+                    break;
+                }
+            }
+        }
+
+        let _ = CloseHandle(snap);
+    }
+
+    let mut tree: Vec<(u32, String)> = processes
+        .iter()
+        .filter(|(p, _, _)| *p == pid)
+        .map(|(p, _, name)| (*p, name.clone()))
+        .collect();
+    let mut frontier = vec![pid];
+    while let Some(parent) = frontier.pop() {
+        for (child, child_parent, name) in &processes {
+            if *child_parent == parent && !tree.iter().any(|(p, _)| p == child) {
+                tree.push((*child, name.clone()));
+                frontier.push(*child);
+            }
+        }
+    }
+    tree
+}
+
+/// Ask every process of the tree to stop: SIGINT to the group, or Ctrl+Break to the
+/// console process group on Windows (console tools ignore `taskkill` without `/F`).
+pub async fn interrupt_tree(pid: u32) {
+    #[cfg(unix)]
+    signal_group(pid, "-INT").await;
+
+    #[cfg(windows)]
+    unsafe {
+        use windows_sys::Win32::System::Console::{GenerateConsoleCtrlEvent, CTRL_BREAK_EVENT};
+        // `isolate` started the root with CREATE_NEW_PROCESS_GROUP, so its pid is the group id.
+        GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid);
+    }
+}
+
+/// Force-kill every process left in the tree. `members` (from `list_tree`) are killed
+/// by pid on Windows, where the tree is lost once its root has exited.
+pub async fn kill_tree(pid: u32, members: &[(u32, String)]) {
+    #[cfg(unix)]
+    {
+        let _ = members;
+        signal_group(pid, "-KILL").await;
+    }
+
+    #[cfg(windows)]
+    {
+        let mut cmd = Command::new("taskkill");
+        cmd.args(["/T", "/F", "/PID", &pid.to_string()]);
+        for (member, _) in members {
+            cmd.args(["/PID", &member.to_string()]);
+        }
+        let _ = cmd
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+    }
+}
+
+/// "name (pid), name (pid)" for the log.
+pub fn describe(processes: &[(u32, String)]) -> String {
+    processes
+        .iter()
+        .map(|(pid, name)| format!("{} ({})", name, pid))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(unix)]
+async fn signal_group(pgid: u32, signal: &str) {
+    let _ = Command::new("kill")
+        .args([signal, "--", &format!("-{}", pgid)])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await;
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A project entry in the config file.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,28 +14,788 @@ pub struct ProjectConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub editor_target: Option<String>,
+    /// Build target chosen in the target picker (any type: Game, Editor, Server, ...).
+    /// Takes precedence over `editor_target` when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// Target platform passed to UnrealBuildTool. If None, the host platform is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<TargetPlatform>,
+    /// Build configuration passed to UnrealBuildTool. If None, Development is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<BuildConfiguration>,
+    /// Options for cook/package builds run through RunUAT BuildCookRun.
+    #[serde(default)]
+    pub package: PackageSettings,
+    /// Extra arguments for every UBT invocation of this project (passed to packaging
+    /// through `-ubtargs`).
+    #[serde(rename = "extraArgs", default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+    /// Environment variables set for every build process of this project.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Shell commands run in order before the build; a failing one aborts the build.
+    #[serde(
+        rename = "preBuildHooks",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pre_build_hooks: Vec<String>,
+    /// Shell commands run in order after the build, whatever its result.
+    #[serde(
+        rename = "postBuildHooks",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub post_build_hooks: Vec<String>,
+    /// Folders (relative to the project directory) a clean rebuild removes.
+    /// If None, `DEFAULT_CLEAN_DIRS` is used.
+    #[serde(rename = "cleanDirs", default, skip_serializing_if = "Option::is_none")]
+    pub clean_dirs: Option<Vec<String>>,
+    /// Generator used for project files. If None, UBT picks the platform default.
+    #[serde(
+        rename = "projectFileFormat",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub project_file_format: Option<ProjectFileFormat>,
+    /// Move cleaned folders into a timestamped backup instead of deleting them.
+    #[serde(
+        rename = "cleanBackup",
+        default,
+        skip_serializing_if = "std::ops::Not::not"
+    )]
+    pub clean_backup: bool,
+    /// Launched automatically after a successful build. If None, nothing is launched.
+    #[serde(
+        rename = "autoLaunch",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub auto_launch: Option<LaunchMode>,
+    /// Multiplayer launch profiles (server plus clients).
+    #[serde(
+        rename = "launchProfiles",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub launch_profiles: Vec<LaunchProfile>,
+    /// Named build presets shown in the Build panel.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<BuildPreset>,
+    /// Name of the preset builds use. If None, the project-level settings above are used.
+    #[serde(
+        rename = "activePreset",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub active_preset: Option<String>,
+}
+
+/// Clean entry standing for the `Binaries` folder of every plugin under `Plugins/`.
+pub const PLUGIN_BINARIES: &str = "Plugins/**/Binaries";
+/// Clean entry standing for the `Intermediate` folder of every plugin under `Plugins/`.
+pub const PLUGIN_INTERMEDIATE: &str = "Plugins/**/Intermediate";
+
+/// Folders a clean rebuild removes until the user picks others. `Saved` is left out
+/// because it holds local config and autosaves.
+pub const DEFAULT_CLEAN_DIRS: [&str; 5] = [
+    "Binaries",
+    "Intermediate",
+    ".vs",
+    PLUGIN_BINARIES,
+    PLUGIN_INTERMEDIATE,
+];
+
+impl ProjectConfig {
+    /// Folders a clean rebuild of this project removes, relative to the project directory.
+    pub fn clean_dirs(&self) -> Vec<String> {
+        match &self.clean_dirs {
+            Some(dirs) => dirs.clone(),
+            None => DEFAULT_CLEAN_DIRS.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    /// The explicitly chosen target to build, if any (picker choice, then editor override).
+    pub fn target_override(&self) -> Option<String> {
+        self.target.clone().or_else(|| self.editor_target.clone())
+    }
+
+    /// The platform builds of this project target (falls back to the host platform).
+    pub fn platform(&self) -> TargetPlatform {
+        self.platform.unwrap_or_else(TargetPlatform::host)
+    }
+
+    /// The configuration builds of this project use (falls back to Development).
+    pub fn configuration(&self) -> BuildConfiguration {
+        self.configuration.unwrap_or_default()
+    }
+
+    /// The preset currently selected in the Build panel, if any.
+    pub fn active_preset(&self) -> Option<&BuildPreset> {
+        self.active_preset
+            .as_ref()
+            .and_then(|name| self.presets.iter().find(|p| &p.name == name))
+    }
+
+    pub fn active_preset_mut(&mut self) -> Option<&mut BuildPreset> {
+        let name = self.active_preset.clone()?;
+        self.presets.iter_mut().find(|p| p.name == name)
+    }
+
+    /// Resolve what a build of this project runs: the active preset if there is one,
+    /// otherwise the project-level settings. `mode` overrides the preset's build mode.
+    /// Project arguments and environment always apply; a preset's are added on top.
+    pub fn build_settings(&self, mode: Option<BuildMode>) -> BuildSettings {
+        let mut settings = BuildSettings {
+            target: self.target_override(),
+            platform: self.platform(),
+            configuration: self.configuration(),
+            mode: mode.unwrap_or(BuildMode::Standard),
+            package: self.package.clone(),
+            extra_args: self.extra_args.clone(),
+            package_args: Vec::new(),
+            env: self.env.clone(),
+            pre_build_hooks: self.pre_build_hooks.clone(),
+            post_build_hooks: self.post_build_hooks.clone(),
+            clean_dirs: self.clean_dirs(),
+            clean_backup: self.clean_backup,
+            project_file_format: self.project_file_format,
+            command: None,
+        };
+        if let Some(preset) = self.active_preset() {
+            if preset.target.is_some() {
+                settings.target = preset.target.clone();
+            }
+            settings.platform = preset.platform;
+            settings.configuration = preset.configuration;
+            settings.mode = mode.unwrap_or(preset.mode);
+            // A package preset's arguments are for BuildCookRun, not the UBT it runs.
+            if settings.mode == BuildMode::Package {
+                settings.package_args = preset.extra_args.clone();
+            } else {
+                settings
+                    .extra_args
+                    .extend(preset.extra_args.iter().cloned());
+            }
+            settings.env.extend(preset.env.clone());
+            // Other modes picked explicitly (clean rebuild, package) run the engine tools.
+            if mode.is_none() {
+                settings.command = preset.command.clone();
+            }
+        }
+        settings
+    }
+}
+
+/// A named combination of build options stored per project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildPreset {
+    pub name: String,
+    /// Target to build. If None, the project's target (or auto-detection) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    pub platform: TargetPlatform,
+    pub configuration: BuildConfiguration,
+    pub mode: BuildMode,
+    /// Extra arguments appended to the UBT / RunUAT command line.
+    #[serde(rename = "extraArgs", default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+    /// Environment variables set for the build process.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Shell command a build of this preset runs instead of UBT / RunUAT (a commandlet,
+    /// automation tests, a custom cook). Supports the hook placeholders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+/// A set of server and client processes started together for multiplayer testing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LaunchProfile {
+    pub name: String,
+    /// Map the server (or each client, without a server) opens. Empty uses the default map.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub map: String,
+    pub server: ServerMode,
+    pub clients: u32,
+    pub port: u16,
+    pub log: bool,
+    pub windowed: bool,
+    /// Window size of game instances as `WIDTHxHEIGHT`. If None, the engine default is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    /// Extra arguments appended to every process of the profile.
+    #[serde(rename = "extraArgs", default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+}
+
+impl LaunchProfile {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            map: String::new(),
+            server: ServerMode::Dedicated,
+            clients: 2,
+            port: 7777,
+            log: true,
+            windowed: true,
+            resolution: Some("1280x720".to_string()),
+            extra_args: Vec::new(),
+        }
+    }
+
+    /// The resolution as `(width, height)`, if it is set and well formed.
+    pub fn resolution_size(&self) -> Option<(u32, u32)> {
+        let (w, h) = self.resolution.as_deref()?.split_once(['x', 'X'])?;
+        Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+    }
+}
+
+/// What kind of server a launch profile starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ServerMode {
+    /// A `-server` process without a window; clients connect to it.
+    Dedicated,
+    /// A game instance opening the map with `?listen`; clients connect to it.
+    Listen,
+    /// No server; each client opens the map on its own.
+    None,
+}
+
+impl ServerMode {
+    pub const ALL: [ServerMode; 3] = [ServerMode::Dedicated, ServerMode::Listen, ServerMode::None];
+
+    /// The next mode in `ALL`, wrapping around.
+    pub fn next(&self) -> Self {
+        let pos = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::fmt::Display for ServerMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServerMode::Dedicated => write!(f, "Dedicated"),
+            ServerMode::Listen => write!(f, "Listen"),
+            ServerMode::None => write!(f, "None"),
+        }
+    }
+}
+
+/// Everything that decides how a single build runs, resolved from a project or preset.
+#[derive(Debug, Clone)]
+pub struct BuildSettings {
+    pub target: Option<String>,
+    pub platform: TargetPlatform,
+    pub configuration: BuildConfiguration,
+    pub mode: BuildMode,
+    pub package: PackageSettings,
+    /// Arguments for UnrealBuildTool.
+    pub extra_args: Vec<String>,
+    /// Arguments for `RunUAT BuildCookRun`, from the active preset of a package build.
+    pub package_args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub pre_build_hooks: Vec<String>,
+    pub post_build_hooks: Vec<String>,
+    /// Folders removed by a clean rebuild, relative to the project directory.
+    pub clean_dirs: Vec<String>,
+    /// Move cleaned folders into a backup instead of deleting them.
+    pub clean_backup: bool,
+    /// Generator for project files; None lets UBT choose.
+    pub project_file_format: Option<ProjectFileFormat>,
+    /// Shell command run instead of UBT / RunUAT, from the active preset.
+    pub command: Option<String>,
+}
+
+/// A folder offered in the clean dialog.
+#[derive(Debug, Clone)]
+pub struct CleanCandidate {
+    /// Path relative to the project directory, e.g. "Plugins/MyPlugin/Binaries".
+    pub path: String,
+    /// Size on disk in bytes, once it has been measured.
+    pub size: Option<u64>,
+}
+
+/// Whether a hook runs before or after the build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookStage {
+    Pre,
+    Post,
+}
+
+/// A platform UnrealBuildTool can build for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TargetPlatform {
+    Win64,
+    Linux,
+    LinuxArm64,
+    Mac,
+    Android,
+}
+
+impl TargetPlatform {
+    pub const ALL: [TargetPlatform; 5] = [
+        TargetPlatform::Win64,
+        TargetPlatform::Linux,
+        TargetPlatform::LinuxArm64,
+        TargetPlatform::Mac,
+        TargetPlatform::Android,
+    ];
+
+    /// The platform Stellar is currently running on.
+    pub fn host() -> Self {
+        if cfg!(windows) {
+            TargetPlatform::Win64
+        } else if cfg!(target_os = "macos") {
+            TargetPlatform::Mac
+        } else if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
+            TargetPlatform::LinuxArm64
+        } else {
+            TargetPlatform::Linux
+        }
+    }
+
+    /// The platform name as UnrealBuildTool expects it on the command line.
+    pub fn ubt_name(&self) -> &'static str {
+        match self {
+            TargetPlatform::Win64 => "Win64",
+            TargetPlatform::Linux => "Linux",
+            TargetPlatform::LinuxArm64 => "LinuxArm64",
+            TargetPlatform::Mac => "Mac",
+            TargetPlatform::Android => "Android",
+        }
+    }
+
+    /// The next platform in `ALL`, wrapping around.
+    pub fn next(&self) -> Self {
+        let pos = Self::ALL.iter().position(|p| p == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::fmt::Display for TargetPlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ubt_name())
+    }
 }
 
 /// Top-level persisted config (compatible with the Tauri app's JSON format).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     pub projects: Vec<ProjectConfig>,
     #[serde(rename = "unrealEnginePath")]
     pub unreal_engine_path: Option<String>,
     #[serde(rename = "selectedProjectPath", default)]
     pub selected_project_path: Option<String>,
+    /// Command opening a source location, with `{file}`, `{line}` and `{column}` placeholders.
+    /// If None, a command is derived from `$VISUAL` / `$EDITOR`.
+    #[serde(
+        rename = "editorCommand",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub editor_command: Option<String>,
+    /// Days a clean backup is kept before it is pruned (0 keeps them forever).
+    /// If None, `DEFAULT_BACKUP_MAX_AGE_DAYS` is used.
+    #[serde(
+        rename = "cleanBackupMaxAgeDays",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub clean_backup_max_age_days: Option<u32>,
+    /// Seconds a cancelled build gets to exit after the interrupt before it is force-killed.
+    /// If None, `DEFAULT_CANCEL_TIMEOUT_SECS` is used.
+    #[serde(
+        rename = "cancelTimeoutSecs",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cancel_timeout_secs: Option<u64>,
 }
 
-impl Default for Config {
+/// Seconds a cancelled process tree gets to exit after the interrupt unless
+/// `cancelTimeoutSecs` says otherwise.
+pub const DEFAULT_CANCEL_TIMEOUT_SECS: u64 = 10;
+
+/// Days a clean backup is kept unless `cleanBackupMaxAgeDays` says otherwise.
+pub const DEFAULT_BACKUP_MAX_AGE_DAYS: u32 = 7;
+
+impl Config {
+    /// How long a cancelled process tree gets to exit before it is force-killed.
+    pub fn cancel_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(
+            self.cancel_timeout_secs
+                .unwrap_or(DEFAULT_CANCEL_TIMEOUT_SECS),
+        )
+    }
+
+    pub fn clean_backup_max_age_days(&self) -> u32 {
+        self.clean_backup_max_age_days
+            .unwrap_or(DEFAULT_BACKUP_MAX_AGE_DAYS)
+    }
+}
+
+/// Per-project options for `RunUAT BuildCookRun`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageSettings {
+    /// Cook flavour for platforms that have them (e.g. "ASTC" or "ETC2" on Android).
+    #[serde(
+        rename = "cookFlavor",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cook_flavor: Option<String>,
+    /// Archive directory. If None, UAT archives into the project's default location.
+    #[serde(rename = "outputDir", default, skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<String>,
+    pub pak: bool,
+    pub stage: bool,
+    pub archive: bool,
+}
+
+impl Default for PackageSettings {
     fn default() -> Self {
         Self {
-            projects: vec![],
-            unreal_engine_path: None,
-            selected_project_path: None,
+            cook_flavor: None,
+            output_dir: None,
+            pak: true,
+            stage: true,
+            archive: true,
+        }
+    }
+}
+
+/// The `Type = TargetType.X` of a `*.Target.cs` rules file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TargetKind {
+    Editor,
+    Game,
+    Client,
+    Server,
+    Program,
+}
+
+impl TargetKind {
+    /// Parse the identifier that follows `TargetType.` in a rules file.
+    pub fn from_rules_name(name: &str) -> Option<Self> {
+        match name {
+            "Editor" => Some(TargetKind::Editor),
+            "Game" => Some(TargetKind::Game),
+            "Client" => Some(TargetKind::Client),
+            "Server" => Some(TargetKind::Server),
+            "Program" => Some(TargetKind::Program),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TargetKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetKind::Editor => write!(f, "Editor"),
+            TargetKind::Game => write!(f, "Game"),
+            TargetKind::Client => write!(f, "Client"),
+            TargetKind::Server => write!(f, "Server"),
+            TargetKind::Program => write!(f, "Program"),
+        }
+    }
+}
+
+/// A build target discovered from a project's `Source/` directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildTarget {
+    pub name: String,
+    pub kind: TargetKind,
+}
+
+/// An UnrealBuildTool build configuration.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuildConfiguration {
+    Debug,
+    DebugGame,
+    #[default]
+    Development,
+    Shipping,
+    Test,
+}
+
+impl BuildConfiguration {
+    pub const ALL: [BuildConfiguration; 5] = [
+        BuildConfiguration::DebugGame,
+        BuildConfiguration::Development,
+        BuildConfiguration::Shipping,
+        BuildConfiguration::Test,
+        BuildConfiguration::Debug,
+    ];
+
+    /// The configuration name as UnrealBuildTool expects it on the command line.
+    pub fn ubt_name(&self) -> &'static str {
+        match self {
+            BuildConfiguration::Debug => "Debug",
+            BuildConfiguration::DebugGame => "DebugGame",
+            BuildConfiguration::Development => "Development",
+            BuildConfiguration::Shipping => "Shipping",
+            BuildConfiguration::Test => "Test",
+        }
+    }
+
+    /// The next configuration in `ALL`, wrapping around.
+    pub fn next(&self) -> Self {
+        let pos = Self::ALL.iter().position(|c| c == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::fmt::Display for BuildConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.ubt_name())
+    }
+}
+
+/// What kind of job a build runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuildMode {
+    Standard,
+    CleanRebuild,
+    /// Build, cook and package through `RunUAT BuildCookRun`.
+    Package,
+    /// Generate IDE project files without building.
+    GenerateProjectFiles,
+}
+
+impl BuildMode {
+    pub const ALL: [BuildMode; 4] = [
+        BuildMode::Standard,
+        BuildMode::CleanRebuild,
+        BuildMode::Package,
+        BuildMode::GenerateProjectFiles,
+    ];
+
+    /// Whether the job compiles the project's own binaries (so they can be launched
+    /// or listed afterwards).
+    pub fn builds_binaries(&self) -> bool {
+        matches!(self, BuildMode::Standard | BuildMode::CleanRebuild)
+    }
+
+    /// The next mode in `ALL`, wrapping around.
+    pub fn next(&self) -> Self {
+        let pos = Self::ALL.iter().position(|m| m == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::fmt::Display for BuildMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildMode::Standard => write!(f, "Build"),
+            BuildMode::CleanRebuild => write!(f, "Clean Rebuild"),
+            BuildMode::Package => write!(f, "Package"),
+            BuildMode::GenerateProjectFiles => write!(f, "Project Files"),
+        }
+    }
+}
+
+/// IDE project format generated by UBT's `-ProjectFiles` mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectFileFormat {
+    VisualStudio,
+    VSCode,
+    Rider,
+    Makefile,
+    CMake,
+    Xcode,
+    QMake,
+}
+
+impl ProjectFileFormat {
+    pub const ALL: [ProjectFileFormat; 7] = [
+        ProjectFileFormat::VisualStudio,
+        ProjectFileFormat::VSCode,
+        ProjectFileFormat::Rider,
+        ProjectFileFormat::Makefile,
+        ProjectFileFormat::CMake,
+        ProjectFileFormat::Xcode,
+        ProjectFileFormat::QMake,
+    ];
+
+    /// Value of UBT's `-ProjectFileFormat=` argument.
+    pub fn ubt_name(&self) -> &'static str {
+        match self {
+            ProjectFileFormat::VisualStudio => "VisualStudio",
+            ProjectFileFormat::VSCode => "VisualStudioCode",
+            ProjectFileFormat::Rider => "Rider",
+            ProjectFileFormat::Makefile => "Make",
+            ProjectFileFormat::CMake => "CMake",
+            ProjectFileFormat::Xcode => "XCode",
+            ProjectFileFormat::QMake => "QMake",
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectFileFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectFileFormat::VisualStudio => write!(f, "Visual Studio"),
+            ProjectFileFormat::VSCode => write!(f, "VS Code"),
+            ProjectFileFormat::Rider => write!(f, "Rider"),
+            ProjectFileFormat::Makefile => write!(f, "Makefile"),
+            ProjectFileFormat::CMake => write!(f, "CMake"),
+            ProjectFileFormat::Xcode => write!(f, "Xcode"),
+            ProjectFileFormat::QMake => write!(f, "QMake"),
+        }
+    }
+}
+
+/// How a project is started from Stellar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LaunchMode {
+    /// Open the project in the editor.
+    Editor,
+    /// Run the project standalone with `-game`.
+    Game,
+}
+
+impl std::fmt::Display for LaunchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LaunchMode::Editor => write!(f, "Editor"),
+            LaunchMode::Game => write!(f, "Game"),
+        }
+    }
+}
+
+/// Status of a job in the build queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl std::fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobStatus::Pending => write!(f, "Pending"),
+            JobStatus::Running => write!(f, "Running"),
+            JobStatus::Succeeded => write!(f, "Done"),
+            JobStatus::Failed => write!(f, "Failed"),
+            JobStatus::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+/// A build waiting in, running from, or finished in the build queue.
+/// Settings are captured when the job is enqueued.
+#[derive(Debug, Clone)]
+pub struct QueuedJob {
+    pub project_name: String,
+    pub project_path: String,
+    /// Name of the preset the job was created from, if any.
+    pub preset: Option<String>,
+    pub settings: BuildSettings,
+    pub status: JobStatus,
+}
+
+impl QueuedJob {
+    pub fn new(project: &ProjectConfig, mode: Option<BuildMode>) -> Self {
+        Self {
+            project_name: project.name.clone(),
+            project_path: project.path.clone(),
+            preset: project.active_preset().map(|p| p.name.clone()),
+            settings: project.build_settings(mode),
+            status: JobStatus::Pending,
+        }
+    }
+
+    /// Short description, e.g. "Build MyGame (Development Win64)".
+    pub fn label(&self) -> String {
+        let mut label = format!(
+            "{} {} ({} {})",
+            self.settings.mode,
+            self.project_name,
+            self.settings.configuration,
+            self.settings.platform
+        );
+        if let Some(preset) = &self.preset {
+            label.push_str(&format!(" [{}]", preset));
         }
+        label
     }
 }
 
+/// How a recorded build ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BuildResult {
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl std::fmt::Display for BuildResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildResult::Succeeded => write!(f, "Success"),
+            BuildResult::Failed => write!(f, "Failed"),
+            BuildResult::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+/// One finished build, persisted in the history file next to `config.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildRecord {
+    #[serde(rename = "projectName")]
+    pub project_name: String,
+    #[serde(rename = "projectPath")]
+    pub project_path: String,
+    /// Explicit target of the build. If None, the editor target was detected automatically.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    pub platform: TargetPlatform,
+    pub configuration: BuildConfiguration,
+    pub mode: BuildMode,
+    /// Start time in seconds since the Unix epoch.
+    #[serde(rename = "startedAt")]
+    pub started_at: i64,
+    #[serde(rename = "durationSecs")]
+    pub duration_secs: u64,
+    pub result: BuildResult,
+    pub errors: usize,
+    pub warnings: usize,
+    #[serde(
+        rename = "engineVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub engine_version: Option<String>,
+    /// Saved build output. If None, the log could not be written.
+    #[serde(rename = "logFile", default, skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<String>,
+}
+
+/// What the end-of-build summary shows about the last finished job.
+#[derive(Debug, Clone)]
+pub struct BuildSummary {
+    pub job: QueuedJob,
+    pub success: bool,
+    pub duration_secs: u64,
+    /// Exit code of the last process the build ran, if it exited on its own.
+    pub exit_code: Option<i32>,
+    /// Why the build failed; None on success.
+    pub failure: Option<String>,
+    pub errors: usize,
+    pub warnings: usize,
+    /// The first few errors, formatted for display.
+    pub first_errors: Vec<String>,
+    /// Slowest actions with their durations in seconds, when the log reports them.
+    pub slowest_actions: Vec<(String, f64)>,
+    /// Binaries written by the build.
+    pub binaries: Vec<String>,
+}
+
 /// A detected Unreal Engine installation.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -67,6 +828,42 @@ impl std::fmt::Display for BuildState {
     }
 }
 
+/// Step of a build that produced an event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildPhase {
+    PreBuildHooks,
+    Clean,
+    GenerateProjectFiles,
+    Compile,
+    Package,
+    /// A preset's own command.
+    Command,
+    PostBuildHooks,
+}
+
+impl std::fmt::Display for BuildPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildPhase::PreBuildHooks => write!(f, "Pre-build hooks"),
+            BuildPhase::Clean => write!(f, "Cleaning"),
+            BuildPhase::GenerateProjectFiles => write!(f, "Generating project files"),
+            BuildPhase::Compile => write!(f, "Compiling"),
+            BuildPhase::Package => write!(f, "Packaging"),
+            BuildPhase::Command => write!(f, "Running command"),
+            BuildPhase::PostBuildHooks => write!(f, "Post-build hooks"),
+        }
+    }
+}
+
+/// Where a line of output came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+    /// A message written by Stellar itself.
+    Stellar,
+}
+
 /// A single line of build output with a severity hint.
 #[derive(Debug, Clone)]
 pub struct LogLine {
@@ -82,6 +879,39 @@ pub enum LogLevel {
     Success,
 }
 
+/// Severity of a compiler or build tool diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Which tool's output format a diagnostic was parsed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSource {
+    Msvc,
+    Clang,
+    /// UnrealHeaderTool.
+    Uht,
+    /// UnrealBuildTool itself (no source location).
+    Ubt,
+}
+
+/// An error or warning parsed from the build log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub source: DiagnosticSource,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    /// Compiler code such as "C2065", if the format has one.
+    pub code: Option<String>,
+    pub message: String,
+    /// Index of the originating line in the build log.
+    pub log_index: usize,
+}
+
 /// A single focusable UI element in the linear navigation order.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FocusItem {
@@ -95,6 +925,8 @@ pub enum FocusItem {
     BuildButton(usize),
     /// The log panel.
     Logs,
+    /// The problems panel (shown while the build log has diagnostics).
+    Problems,
 }
 
 impl FocusItem {
@@ -105,6 +937,7 @@ impl FocusItem {
             FocusItem::Engine => FocusPanel::Engine,
             FocusItem::BuildButton(_) => FocusPanel::Build,
             FocusItem::Logs => FocusPanel::Logs,
+            FocusItem::Problems => FocusPanel::Problems,
         }
     }
 }
@@ -116,6 +949,7 @@ pub enum FocusPanel {
     Engine,
     Build,
     Logs,
+    Problems,
 }
 
 /// Active modal dialog type.
@@ -135,6 +969,62 @@ pub enum DialogKind {
         candidates: Vec<String>,
         selected: usize,
     },
+    /// Pick the project file generator and generate. Index 0 is "UBT default",
+    /// `ProjectFileFormat::ALL[i]` is at index `i + 1`.
+    ProjectFilesPicker {
+        project_index: usize,
+        selected: usize,
+    },
+    /// Pick the build target (any type) for a specific project.
+    /// Index 0 is "auto-detect", target `i` is at index `i + 1`.
+    TargetPicker {
+        project_index: usize,
+        targets: Vec<BuildTarget>,
+        selected: usize,
+    },
+    /// Edit the package (BuildCookRun) options of a project, one row per option.
+    PackageOptions {
+        project_index: usize,
+        selected: usize,
+    },
+    /// List of a project's build presets.
+    Presets {
+        project_index: usize,
+        selected: usize,
+    },
+    /// Edit one build preset, one row per field.
+    PresetEditor {
+        project_index: usize,
+        preset_index: usize,
+        selected: usize,
+    },
+    /// Launch profiles of a project; Enter starts the selected one.
+    LaunchProfiles {
+        project_index: usize,
+        selected: usize,
+    },
+    /// Pick the folders a clean rebuild removes, with their sizes.
+    Clean {
+        project_index: usize,
+        selected: usize,
+    },
+    /// Summary of the build that just finished, with quick actions.
+    BuildSummary,
+    /// Recorded builds, newest first; Enter reopens the selected build's log.
+    History { selected: usize },
+    /// Edit one launch profile, one row per field.
+    LaunchProfileEditor {
+        project_index: usize,
+        profile_index: usize,
+        selected: usize,
+    },
+    /// Pre- and post-build hooks of a project. Rows list pre hooks first, then post hooks.
+    Hooks {
+        project_index: usize,
+        selected: usize,
+    },
+    /// The build queue (selected row).
+    BuildQueue { selected: usize },
     /// Confirm an action (message, confirmed action tag).
     Confirm {
         message: String,
@@ -149,9 +1039,32 @@ pub enum PathInputTarget {
     AddProject,
     SetEnginePath,
     SetEditorTarget(usize),
+    SetBuildTarget(usize),
+    SetCookFlavor(usize),
+    SetPackageOutputDir(usize),
+    SetExtraArgs(usize),
+    SetEnv(usize),
+    /// New hook command (project index, stage).
+    AddHook(usize, HookStage),
+    /// Edited hook command (project index, stage, hook index).
+    EditHook(usize, HookStage, usize),
+    /// New preset name for a project.
+    NewPreset(usize),
+    /// A text field of a preset (project index, preset index, editor row).
+    PresetField(usize, usize, usize),
+    /// Command template used to open source locations (empty resets to the default).
+    SetEditorCommand,
+    /// New launch profile name for a project.
+    NewLaunchProfile(usize),
+    /// A text field of a launch profile (project index, profile index, editor row).
+    LaunchProfileField(usize, usize, usize),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfirmAction {
     RemoveProject(usize),
+    /// Delete a preset (project index, preset index).
+    DeletePreset(usize, usize),
+    /// Delete a launch profile (project index, profile index).
+    DeleteLaunchProfile(usize, usize),
 }
@@ -1,10 +1,10 @@
 use super::theme;
 use crate::app::App;
-use crate::types::FocusPanel;
+use crate::types::{BuildConfiguration, BuildState, FocusPanel, TargetPlatform};
 use ratatui::layout::Rect;
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, LineGauge, Paragraph};
 use ratatui::Frame;
 
 const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
@@ -25,13 +25,37 @@ pub fn draw_build_controls(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(block, area);
 
     // Status line with spinner
-    let status_text = match &app.build_state {
-        crate::types::BuildState::Running => {
+    let mut status_text = match &app.build_state {
+        BuildState::Running if app.build_cancelling => {
+            let frame = SPINNER_FRAMES[app.tick as usize / 3 % SPINNER_FRAMES.len()];
+            format!("  {} STATUS: Cancelling...", frame)
+        }
+        BuildState::Running => {
             let frame = SPINNER_FRAMES[app.tick as usize / 3 % SPINNER_FRAMES.len()];
             format!("  {} STATUS: {}", frame, app.build_state)
         }
         _ => format!("  STATUS: {}", app.build_state),
     };
+    if app.build_state != BuildState::Idle {
+        if let Some(label) = &app.build_label {
+            status_text.push_str(&format!("  ({})", label));
+        }
+    }
+    if app.build_state == BuildState::Running && !app.build_cancelling {
+        if let Some(phase) = app.build_phase {
+            status_text.push_str(&format!("  {}...", phase));
+        }
+    }
+    let pending = app.pending_job_count();
+    if pending > 0 {
+        status_text.push_str(&format!("  Queue: {} pending", pending));
+    }
+    if let Some(session) = &app.launch_session {
+        let running = session.running_count();
+        if running > 0 {
+            status_text.push_str(&format!("  {}: {} running", session.profile_name, running));
+        }
+    }
 
     let actions = app.available_build_actions();
     let focused_btn = app.focused_build_button();
@@ -52,6 +76,12 @@ pub fn draw_build_controls(f: &mut Frame, area: Rect, app: &App) {
         let shortcut = match label {
             "Build" => "b",
             "Clean Rebuild" => "n",
+            "Package" => "k",
+            "Project Files" => "F",
+            "Editor" => "e",
+            "Game" => "G",
+            "Queue" => "u",
+            "Stop All" => "X",
             "Cancel" => "c",
             "Clear" => "x",
             "Copy Log" => "y",
@@ -72,14 +102,162 @@ pub fn draw_build_controls(f: &mut Frame, area: Rect, app: &App) {
         button_spans.push(Span::raw(" "));
     }
 
+    let project = app.selected_project();
+    let settings = project.map(|p| p.build_settings(None));
+    let platform = settings
+        .as_ref()
+        .map(|s| s.platform)
+        .unwrap_or_else(TargetPlatform::host);
+    let configuration = settings
+        .as_ref()
+        .map(|s| s.configuration)
+        .unwrap_or(BuildConfiguration::Development);
+    let target = match settings.and_then(|s| s.target) {
+        Some(name) => name,
+        None => "Auto (editor)".to_string(),
+    };
+    let preset = project
+        .and_then(|p| p.active_preset())
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| "None".to_string());
+    let mut target_spans = vec![
+        Span::styled("  Preset: ", Style::default().fg(theme::TEXT_DIM)),
+        Span::styled(preset, Style::default().fg(theme::TEXT)),
+    ];
+    if focused {
+        target_spans.push(Span::styled(" [s]", theme::key_hint_style()));
+    }
+    target_spans.push(Span::styled(
+        "   Target: ",
+        Style::default().fg(theme::TEXT_DIM),
+    ));
+    target_spans.push(Span::styled(target, Style::default().fg(theme::TEXT)));
+    if focused {
+        target_spans.push(Span::styled(" [t]", theme::key_hint_style()));
+    }
+    let auto_launch = project
+        .and_then(|p| p.auto_launch)
+        .map(|m| m.to_string())
+        .unwrap_or_else(|| "Off".to_string());
+    target_spans.push(Span::styled(
+        "   Auto-launch: ",
+        Style::default().fg(theme::TEXT_DIM),
+    ));
+    target_spans.push(Span::styled(auto_launch, Style::default().fg(theme::TEXT)));
+    if focused {
+        target_spans.push(Span::styled(" [L]", theme::key_hint_style()));
+    }
+
+    let mut option_spans = vec![
+        Span::styled("  Platform: ", Style::default().fg(theme::TEXT_DIM)),
+        Span::styled(platform.to_string(), Style::default().fg(theme::TEXT)),
+    ];
+    if focused {
+        option_spans.push(Span::styled(" [p]", theme::key_hint_style()));
+    }
+    option_spans.push(Span::styled(
+        "   Config: ",
+        Style::default().fg(theme::TEXT_DIM),
+    ));
+    option_spans.push(Span::styled(
+        configuration.to_string(),
+        Style::default().fg(theme::TEXT),
+    ));
+    if focused {
+        option_spans.push(Span::styled(" [g]", theme::key_hint_style()));
+    }
+    if let Some(project) = project {
+        if !project.extra_args.is_empty() || !project.env.is_empty() {
+            option_spans.push(Span::styled(
+                format!(
+                    "   +{} args, {} env",
+                    project.extra_args.len(),
+                    project.env.len()
+                ),
+                Style::default().fg(theme::TEXT_DIM),
+            ));
+        }
+    }
+    if focused {
+        option_spans.push(Span::styled(" [a/v]", theme::key_hint_style()));
+    }
+    if let Some(project) = project {
+        let hooks = project.pre_build_hooks.len() + project.post_build_hooks.len();
+        if hooks > 0 {
+            option_spans.push(Span::styled(
+                format!("   {} hooks", hooks),
+                Style::default().fg(theme::TEXT_DIM),
+            ));
+            if focused {
+                option_spans.push(Span::styled(" [h]", theme::key_hint_style()));
+            }
+        }
+    }
+
     let lines = vec![
         Line::from(Span::styled(
             status_text,
             theme::status_style(&app.build_state),
         )),
         Line::from(""),
+        Line::from(target_spans),
+        Line::from(option_spans),
         Line::from(button_spans),
     ];
 
     f.render_widget(Paragraph::new(lines), inner);
+
+    if app.build_state == BuildState::Running && inner.height > 1 {
+        let row = Rect {
+            y: inner.y + 1,
+            height: 1,
+            ..inner
+        };
+        draw_progress(f, row, app);
+    }
+}
+
+/// Gauge of UBT actions done, with elapsed time and ETA. Falls back to plain timing
+/// text until the first `[n/m]` counter shows up.
+fn draw_progress(f: &mut Frame, area: Rect, app: &App) {
+    let elapsed = app
+        .build_elapsed_secs()
+        .map(crate::app::format_duration)
+        .unwrap_or_default();
+    let eta = app
+        .build_eta_secs()
+        .map(|secs| format!("  ETA {}", crate::app::format_duration(secs)))
+        .unwrap_or_default();
+
+    let Some((done, total)) = app.build_progress else {
+        let text = format!("  Elapsed {}{}", elapsed, eta);
+        f.render_widget(
+            Paragraph::new(Span::styled(text, Style::default().fg(theme::TEXT_DIM))),
+            area,
+        );
+        return;
+    };
+
+    let ratio = done as f64 / total as f64;
+    let area = Rect {
+        x: area.x + 2,
+        width: area.width.saturating_sub(4),
+        ..area
+    };
+    let gauge = LineGauge::default()
+        .ratio(ratio)
+        .label(Span::styled(
+            format!(
+                "{:>3}% {}/{}  {}{} ",
+                (ratio * 100.0) as u32,
+                done,
+                total,
+                elapsed,
+                eta
+            ),
+            Style::default().fg(theme::TEXT),
+        ))
+        .filled_style(Style::default().fg(theme::SUCCESS))
+        .unfilled_style(Style::default().fg(theme::BORDER));
+    f.render_widget(gauge, area);
 }
@@ -1,6 +1,6 @@
 use super::theme;
 use crate::app::App;
-use crate::types::DialogKind;
+use crate::types::{BuildResult, BuildTarget, DialogKind, JobStatus, ProjectFileFormat};
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
@@ -28,42 +28,903 @@ pub fn draw_dialog(f: &mut Frame, area: Rect, app: &App) {
         } => {
             draw_editor_target_picker(f, area, app, *project_index, candidates, *selected);
         }
+        DialogKind::TargetPicker {
+            project_index,
+            targets,
+            selected,
+        } => {
+            draw_target_picker(f, area, app, *project_index, targets, *selected);
+        }
+        DialogKind::ProjectFilesPicker {
+            project_index,
+            selected,
+        } => {
+            draw_project_files_picker(f, area, app, *project_index, *selected);
+        }
+        DialogKind::PackageOptions {
+            project_index,
+            selected,
+        } => {
+            draw_package_options(f, area, app, *project_index, *selected);
+        }
+        DialogKind::Presets {
+            project_index,
+            selected,
+        } => {
+            draw_presets(f, area, app, *project_index, *selected);
+        }
+        DialogKind::PresetEditor {
+            project_index,
+            preset_index,
+            selected,
+        } => {
+            draw_preset_editor(f, area, app, *project_index, *preset_index, *selected);
+        }
+        DialogKind::LaunchProfiles {
+            project_index,
+            selected,
+        } => {
+            draw_launch_profiles(f, area, app, *project_index, *selected);
+        }
+        DialogKind::LaunchProfileEditor {
+            project_index,
+            profile_index,
+            selected,
+        } => {
+            draw_launch_profile_editor(f, area, app, *project_index, *profile_index, *selected);
+        }
+        DialogKind::Hooks {
+            project_index,
+            selected,
+        } => {
+            draw_hooks(f, area, app, *project_index, *selected);
+        }
+        DialogKind::Clean {
+            project_index,
+            selected,
+        } => {
+            draw_clean(f, area, app, *project_index, *selected);
+        }
+        DialogKind::BuildSummary => {
+            draw_build_summary(f, area, app);
+        }
+        DialogKind::History { selected } => {
+            draw_history(f, area, app, *selected);
+        }
+        DialogKind::BuildQueue { selected } => {
+            draw_build_queue(f, area, app, *selected);
+        }
         DialogKind::Confirm { message, .. } => {
             draw_confirm(f, area, message);
         }
-        DialogKind::Help => {
-            draw_help(f, area);
+        DialogKind::Help => {
+            draw_help(f, area);
+        }
+    }
+}
+
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let vert = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length((area.height.saturating_sub(height)) / 2),
+            Constraint::Length(height),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vert[1])[1]
+}
+
+fn draw_path_input(f: &mut Frame, area: Rect, label: &str, value: &str) {
+    let popup = centered_rect(60, 7, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" {} ", label),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    // Show the text input with a cursor
+    let cursor_char = if (std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+        / 500)
+        .is_multiple_of(2)
+    {
+        "█"
+    } else {
+        " "
+    };
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  > ", Style::default().fg(theme::ACCENT_WARM)),
+            Span::styled(value, Style::default().fg(theme::TEXT)),
+            Span::styled(cursor_char, Style::default().fg(theme::ACCENT)),
+        ]),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  [Enter]", theme::key_hint_style()),
+            Span::styled(" Confirm  ", theme::footer_style()),
+            Span::styled("[Esc]", theme::key_hint_style()),
+            Span::styled(" Cancel", theme::footer_style()),
+        ]),
+    ];
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_engine_picker(f: &mut Frame, area: Rect, app: &App) {
+    let height = (app.engines.len() as u16 * 2 + 6).min(area.height - 4);
+    let popup = centered_rect(60, height, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            " Select Engine ",
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from("")];
+
+    for (i, engine) in app.engines.iter().enumerate() {
+        let selected = i == app.engine_picker_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(&engine.name, style),
+        ]));
+        lines.push(Line::from(vec![
+            Span::raw("   "),
+            Span::styled(&engine.path, Style::default().fg(theme::TEXT_DIM)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Select  ", theme::footer_style()),
+        Span::styled("[m]", theme::key_hint_style()),
+        Span::styled(" Manual  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Cancel", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_editor_target_picker(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    candidates: &[String],
+    selected_index: usize,
+) {
+    let height = (candidates.len() as u16 + 8).min(area.height - 4).max(8);
+    let popup = centered_rect(60, height, area);
+    f.render_widget(Clear, popup);
+
+    let project_name = app
+        .config
+        .projects
+        .get(project_index)
+        .map(|p| p.name.as_str())
+        .unwrap_or("Project");
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Editor Target - {} ", project_name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from(vec![Span::styled(
+        "  Pick the editor target to build.",
+        Style::default().fg(theme::TEXT_DIM),
+    )])];
+    lines.push(Line::from(""));
+
+    for (i, candidate) in candidates.iter().enumerate() {
+        let selected = i == selected_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(candidate, style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Select  ", theme::footer_style()),
+        Span::styled("[m]", theme::key_hint_style()),
+        Span::styled(" Manual  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Cancel", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_target_picker(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    targets: &[BuildTarget],
+    selected_index: usize,
+) {
+    let mut kinds: Vec<_> = targets.iter().map(|t| t.kind).collect();
+    kinds.dedup();
+    let height = (targets.len() as u16 + kinds.len() as u16 * 2 + 9)
+        .min(area.height - 4)
+        .max(9);
+    let popup = centered_rect(60, height, area);
+    f.render_widget(Clear, popup);
+
+    let project_name = app
+        .config
+        .projects
+        .get(project_index)
+        .map(|p| p.name.as_str())
+        .unwrap_or("Project");
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Build Target - {} ", project_name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let item_style = |selected: bool| {
+        if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        }
+    };
+    let marker = |selected: bool| if selected { " > " } else { "   " };
+
+    let mut lines = vec![Line::from(vec![Span::styled(
+        "  Pick the target to build.",
+        Style::default().fg(theme::TEXT_DIM),
+    )])];
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(marker(selected_index == 0), item_style(selected_index == 0)),
+        Span::styled("Auto-detect editor target", item_style(selected_index == 0)),
+    ]));
+
+    let mut current_kind = None;
+    for (i, target) in targets.iter().enumerate() {
+        if current_kind != Some(target.kind) {
+            current_kind = Some(target.kind);
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("  {}", target.kind.to_string().to_uppercase()),
+                Style::default()
+                    .fg(theme::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        }
+        let selected = i + 1 == selected_index;
+        lines.push(Line::from(vec![
+            Span::styled(marker(selected), item_style(selected)),
+            Span::styled(&target.name, item_style(selected)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Select  ", theme::footer_style()),
+        Span::styled("[m]", theme::key_hint_style()),
+        Span::styled(" Manual  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Cancel", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_project_files_picker(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    selected_index: usize,
+) {
+    let popup = centered_rect(50, ProjectFileFormat::ALL.len() as u16 + 8, area);
+    f.render_widget(Clear, popup);
+
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Project Files - {} ", project.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            "  Pick the generator, then generate.",
+            Style::default().fg(theme::TEXT_DIM),
+        )),
+        Line::from(""),
+    ];
+    let names = std::iter::once("UBT default".to_string())
+        .chain(ProjectFileFormat::ALL.iter().map(|f| f.to_string()));
+    for (i, name) in names.enumerate() {
+        let selected = i == selected_index;
+        let current = match i {
+            0 => project.project_file_format.is_none(),
+            _ => project.project_file_format == Some(ProjectFileFormat::ALL[i - 1]),
+        };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        let mut spans = vec![
+            Span::styled(if selected { " > " } else { "   " }, style),
+            Span::styled(name, style),
+        ];
+        if current {
+            spans.push(Span::styled("  (current)", theme::subtitle_style()));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Generate  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Cancel", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_package_options(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    selected_index: usize,
+) {
+    let popup = centered_rect(60, 12, area);
+    f.render_widget(Clear, popup);
+
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+    let package = &project.package;
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Package Options - {} ", project.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let check = |on: bool| if on { "[x]" } else { "[ ]" };
+    let rows = [
+        format!("{} Pak content (-pak)", check(package.pak)),
+        format!("{} Stage build (-stage)", check(package.stage)),
+        format!("{} Archive build (-archive)", check(package.archive)),
+        format!(
+            "Cook flavour: {}",
+            package.cook_flavor.as_deref().unwrap_or("(none)")
+        ),
+        format!(
+            "Output dir:   {}",
+            package.output_dir.as_deref().unwrap_or("(default)")
+        ),
+    ];
+
+    let mut lines = vec![Line::from("")];
+    for (i, row) in rows.into_iter().enumerate() {
+        let selected = i == selected_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(row, style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter/Space]", theme::key_hint_style()),
+        Span::styled(" Toggle/Edit  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_presets(f: &mut Frame, area: Rect, app: &App, project_index: usize, selected_index: usize) {
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+    let height = (project.presets.len() as u16 + 6)
+        .min(area.height - 4)
+        .max(7);
+    let popup = centered_rect(60, height, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Build Presets - {} ", project.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from("")];
+
+    if project.presets.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No presets yet. Press [a] to create one.",
+            theme::subtitle_style(),
+        )));
+    }
+
+    for (i, preset) in project.presets.iter().enumerate() {
+        let selected = i == selected_index;
+        let active = project.active_preset.as_ref() == Some(&preset.name);
+        let marker = if selected {
+            " > "
+        } else if active {
+            " * "
+        } else {
+            "   "
+        };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(&preset.name, style),
+            Span::styled(
+                match &preset.command {
+                    Some(command) => format!("  $ {}", command),
+                    None => format!(
+                        "  {} {} {}",
+                        preset.mode, preset.configuration, preset.platform
+                    ),
+                },
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Use  ", theme::footer_style()),
+        Span::styled("[a]", theme::key_hint_style()),
+        Span::styled(" New  ", theme::footer_style()),
+        Span::styled("[e]", theme::key_hint_style()),
+        Span::styled(" Edit  ", theme::footer_style()),
+        Span::styled("[d]", theme::key_hint_style()),
+        Span::styled(" Delete  ", theme::footer_style()),
+        Span::styled("[n]", theme::key_hint_style()),
+        Span::styled(" None  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_preset_editor(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    preset_index: usize,
+    selected_index: usize,
+) {
+    let Some(preset) = app
+        .config
+        .projects
+        .get(project_index)
+        .and_then(|p| p.presets.get(preset_index))
+    else {
+        return;
+    };
+    let popup = centered_rect(60, 14, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Edit Preset - {} ", preset.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let or_none = |s: String| {
+        if s.is_empty() {
+            "(none)".to_string()
+        } else {
+            s
+        }
+    };
+    let rows = [
+        format!("Name:          {}", preset.name),
+        format!(
+            "Target:        {}",
+            preset.target.as_deref().unwrap_or("(project default)")
+        ),
+        format!("Platform:      {}", preset.platform),
+        format!("Configuration: {}", preset.configuration),
+        format!("Mode:          {}", preset.mode),
+        format!("Extra args:    {}", or_none(preset.extra_args.join(" "))),
+        format!(
+            "Environment:   {}",
+            or_none(crate::app::format_env(&preset.env))
+        ),
+        format!(
+            "Command:       {}",
+            preset.command.as_deref().unwrap_or("(UBT / RunUAT)")
+        ),
+    ];
+
+    let mut lines = vec![Line::from("")];
+    for (i, row) in rows.into_iter().enumerate() {
+        let selected = i == selected_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(row, style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter/Space]", theme::key_hint_style()),
+        Span::styled(" Cycle/Edit  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Back", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_launch_profiles(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    selected_index: usize,
+) {
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+    let height = (project.launch_profiles.len() as u16 + 6)
+        .min(area.height - 4)
+        .max(7);
+    let popup = centered_rect(60, height, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Launch Profiles - {} ", project.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from("")];
+
+    if project.launch_profiles.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No launch profiles yet. Press [a] to create one.",
+            theme::subtitle_style(),
+        )));
+    }
+
+    for (i, profile) in project.launch_profiles.iter().enumerate() {
+        let selected = i == selected_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        let map = if profile.map.is_empty() {
+            "default map"
+        } else {
+            profile.map.as_str()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(&profile.name, style),
+            Span::styled(
+                format!(
+                    "  {} server + {} clients, {}",
+                    profile.server, profile.clients, map
+                ),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter]", theme::key_hint_style()),
+        Span::styled(" Launch  ", theme::footer_style()),
+        Span::styled("[a]", theme::key_hint_style()),
+        Span::styled(" New  ", theme::footer_style()),
+        Span::styled("[e]", theme::key_hint_style()),
+        Span::styled(" Edit  ", theme::footer_style()),
+        Span::styled("[d]", theme::key_hint_style()),
+        Span::styled(" Delete  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_launch_profile_editor(
+    f: &mut Frame,
+    area: Rect,
+    app: &App,
+    project_index: usize,
+    profile_index: usize,
+    selected_index: usize,
+) {
+    let Some(profile) = app
+        .config
+        .projects
+        .get(project_index)
+        .and_then(|p| p.launch_profiles.get(profile_index))
+    else {
+        return;
+    };
+    let popup = centered_rect(60, 15, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Edit Launch Profile - {} ", profile.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let on_off = |v: bool| if v { "On" } else { "Off" };
+    let rows = [
+        format!("Name:        {}", profile.name),
+        format!(
+            "Map:         {}",
+            if profile.map.is_empty() {
+                "(default)"
+            } else {
+                profile.map.as_str()
+            }
+        ),
+        format!("Server:      {}", profile.server),
+        format!("Clients:     {}", profile.clients),
+        format!("Port:        {}", profile.port),
+        format!("-log:        {}", on_off(profile.log)),
+        format!("-windowed:   {}", on_off(profile.windowed)),
+        format!(
+            "Resolution:  {}",
+            profile.resolution.as_deref().unwrap_or("(default)")
+        ),
+        format!(
+            "Extra args:  {}",
+            if profile.extra_args.is_empty() {
+                "(none)".to_string()
+            } else {
+                profile.extra_args.join(" ")
+            }
+        ),
+    ];
+
+    let mut lines = vec![Line::from("")];
+    for (i, row) in rows.into_iter().enumerate() {
+        let selected = i == selected_index;
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(row, style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [Enter/Space]", theme::key_hint_style()),
+        Span::styled(" Cycle/Toggle/Edit  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Back", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_hooks(f: &mut Frame, area: Rect, app: &App, project_index: usize, selected_index: usize) {
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+    let count = project.pre_build_hooks.len() + project.post_build_hooks.len();
+    let height = (count as u16 + 10).min(area.height - 4);
+    let popup = centered_rect(70, height, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            format!(" Build Hooks - {} ", project.name),
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let heading = Style::default()
+        .fg(theme::ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = Vec::new();
+    let sections = [
+        ("  PRE-BUILD", &project.pre_build_hooks, 0),
+        (
+            "  POST-BUILD",
+            &project.post_build_hooks,
+            project.pre_build_hooks.len(),
+        ),
+    ];
+    for (title, hooks, offset) in sections {
+        lines.push(Line::from(Span::styled(title, heading)));
+        if hooks.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "     (none)",
+                theme::subtitle_style(),
+            )));
+        }
+        for (i, hook) in hooks.iter().enumerate() {
+            let selected = offset + i == selected_index;
+            let marker = if selected { " > " } else { "   " };
+            let style = if selected {
+                theme::selected_style().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::TEXT)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(marker, style),
+                Span::styled(format!("{}. {}", i + 1, hook), style),
+            ]));
         }
+        lines.push(Line::from(""));
     }
-}
 
-fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
-    let vert = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length((area.height.saturating_sub(height)) / 2),
-            Constraint::Length(height),
-            Constraint::Min(0),
-        ])
-        .split(area);
+    lines.push(Line::from(vec![
+        Span::styled("  [a/A]", theme::key_hint_style()),
+        Span::styled(" Add pre/post  ", theme::footer_style()),
+        Span::styled("[Enter]", theme::key_hint_style()),
+        Span::styled(" Edit  ", theme::footer_style()),
+        Span::styled("[K/J]", theme::key_hint_style()),
+        Span::styled(" Move  ", theme::footer_style()),
+        Span::styled("[d]", theme::key_hint_style()),
+        Span::styled(" Delete  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
 
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(vert[1])[1]
+    f.render_widget(Paragraph::new(lines), inner);
 }
 
-fn draw_path_input(f: &mut Frame, area: Rect, label: &str, value: &str) {
-    let popup = centered_rect(60, 7, area);
+fn draw_clean(f: &mut Frame, area: Rect, app: &App, project_index: usize, selected_index: usize) {
+    let Some(project) = app.config.projects.get(project_index) else {
+        return;
+    };
+    let dirs = project.clean_dirs();
+    let height = (app.clean_candidates.len() as u16 + 8)
+        .min(area.height - 4)
+        .max(9);
+    let popup = centered_rect(65, height, area);
     f.render_widget(Clear, popup);
 
     let block = Block::default()
         .title(Line::from(vec![Span::styled(
-            format!(" {} ", label),
+            format!(" Clean - {} ", project.name),
             theme::panel_title_style(),
         )]))
         .borders(Borders::ALL)
@@ -73,47 +934,92 @@ fn draw_path_input(f: &mut Frame, area: Rect, label: &str, value: &str) {
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
-    // Show the text input with a cursor
-    let cursor_char = if (std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis()
-        / 500)
-        % 2
-        == 0
-    {
-        "█"
+    let mut lines = vec![Line::from(Span::styled(
+        "  Folders removed by a clean rebuild:",
+        theme::subtitle_style(),
+    ))];
+    if app.clean_candidates.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "     (nothing to clean)",
+            theme::subtitle_style(),
+        )));
+    }
+
+    let mut total = 0;
+    for (i, candidate) in app.clean_candidates.iter().enumerate() {
+        let selected = i == selected_index;
+        let ticked = dirs.contains(&candidate.path);
+        if ticked {
+            total += candidate.size.unwrap_or(0);
+        }
+        let marker = if selected { " > " } else { "   " };
+        let style = if selected {
+            theme::selected_style().add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme::TEXT)
+        };
+        let size = candidate
+            .size
+            .map(crate::app::format_size)
+            .unwrap_or_else(|| "...".to_string());
+        lines.push(Line::from(vec![
+            Span::styled(marker, style),
+            Span::styled(if ticked { "[x] " } else { "[ ] " }, style),
+            Span::styled(format!("{:<40}", candidate.path), style),
+            Span::styled(
+                format!("{:>10}", size),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    let mode = if project.clean_backup {
+        let kept = match app.config.clean_backup_max_age_days() {
+            0 => "kept forever".to_string(),
+            days => format!("kept {} days", days),
+        };
+        format!("Move to {}/ ({})", crate::build::CLEAN_BACKUP_DIR, kept)
     } else {
-        " "
+        "Delete".to_string()
     };
-
-    let lines = vec![
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  > ", Style::default().fg(theme::ACCENT_WARM)),
-            Span::styled(value, Style::default().fg(theme::TEXT)),
-            Span::styled(cursor_char, Style::default().fg(theme::ACCENT)),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("  [Enter]", theme::key_hint_style()),
-            Span::styled(" Confirm  ", theme::footer_style()),
-            Span::styled("[Esc]", theme::key_hint_style()),
-            Span::styled(" Cancel", theme::footer_style()),
-        ]),
-    ];
+    lines.push(Line::from(vec![
+        Span::styled("  Selected: ", Style::default().fg(theme::TEXT_DIM)),
+        Span::styled(
+            crate::app::format_size(total),
+            Style::default().fg(theme::TEXT),
+        ),
+        Span::styled("   Mode: ", Style::default().fg(theme::TEXT_DIM)),
+        Span::styled(mode, Style::default().fg(theme::TEXT)),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  [b]", theme::key_hint_style()),
+        Span::styled(" Backup mode  ", theme::footer_style()),
+        Span::styled("[u]", theme::key_hint_style()),
+        Span::styled(" Restore last clean", theme::footer_style()),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("  [Space]", theme::key_hint_style()),
+        Span::styled(" Toggle  ", theme::footer_style()),
+        Span::styled("[d]", theme::key_hint_style()),
+        Span::styled(" Dry run  ", theme::footer_style()),
+        Span::styled("[r]", theme::key_hint_style()),
+        Span::styled(" Clean rebuild  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
 
     f.render_widget(Paragraph::new(lines), inner);
 }
 
-fn draw_engine_picker(f: &mut Frame, area: Rect, app: &App) {
-    let height = (app.engines.len() as u16 * 2 + 6).min(area.height - 4);
-    let popup = centered_rect(60, height, area);
+fn draw_build_queue(f: &mut Frame, area: Rect, app: &App, selected_index: usize) {
+    let height = (app.queue.len() as u16 + 6).min(area.height - 4).max(7);
+    let popup = centered_rect(70, height, area);
     f.render_widget(Clear, popup);
 
     let block = Block::default()
         .title(Line::from(vec![Span::styled(
-            " Select Engine ",
+            " Build Queue ",
             theme::panel_title_style(),
         )]))
         .borders(Borders::ALL)
@@ -125,59 +1031,182 @@ fn draw_engine_picker(f: &mut Frame, area: Rect, app: &App) {
 
     let mut lines = vec![Line::from("")];
 
-    for (i, engine) in app.engines.iter().enumerate() {
-        let selected = i == app.engine_picker_index;
+    if app.queue.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Queue is empty.",
+            theme::subtitle_style(),
+        )));
+    }
+
+    for (i, job) in app.queue.iter().enumerate() {
+        let selected = i == selected_index;
         let marker = if selected { " > " } else { "   " };
         let style = if selected {
             theme::selected_style().add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(theme::TEXT)
         };
+        let status_style = match job.status {
+            JobStatus::Pending => Style::default().fg(theme::TEXT_DIM),
+            JobStatus::Running => Style::default()
+                .fg(theme::TEXT)
+                .add_modifier(Modifier::BOLD),
+            JobStatus::Succeeded => Style::default().fg(theme::SUCCESS),
+            JobStatus::Failed => Style::default().fg(theme::ERROR),
+            JobStatus::Cancelled => Style::default().fg(theme::WARNING),
+        };
         lines.push(Line::from(vec![
             Span::styled(marker, style),
-            Span::styled(&engine.name, style),
+            Span::styled(format!("{:<10}", job.status.to_string()), status_style),
+            Span::styled(job.label(), style),
         ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  [K/J]", theme::key_hint_style()),
+        Span::styled(" Move up/down  ", theme::footer_style()),
+        Span::styled("[d]", theme::key_hint_style()),
+        Span::styled(" Drop  ", theme::footer_style()),
+        Span::styled("[x]", theme::key_hint_style()),
+        Span::styled(" Clear finished  ", theme::footer_style()),
+        Span::styled("[Esc]", theme::key_hint_style()),
+        Span::styled(" Close", theme::footer_style()),
+    ]));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+fn draw_build_summary(f: &mut Frame, area: Rect, app: &App) {
+    let Some(summary) = &app.last_summary else {
+        return;
+    };
+
+    let heading = Style::default()
+        .fg(theme::ACCENT)
+        .add_modifier(Modifier::BOLD);
+    let dim = Style::default().fg(theme::TEXT_DIM);
+    let text = Style::default().fg(theme::TEXT);
+    let (result, result_style) = if summary.success {
+        ("SUCCEEDED", Style::default().fg(theme::SUCCESS))
+    } else {
+        ("FAILED", Style::default().fg(theme::ERROR))
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                format!("  {}", result),
+                result_style.add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  {}", summary.job.label()), text),
+        ]),
+        Line::from(vec![
+            Span::styled("  Duration: ", dim),
+            Span::styled(crate::app::format_duration(summary.duration_secs), text),
+            Span::styled("   Errors: ", dim),
+            Span::styled(
+                summary.errors.to_string(),
+                if summary.errors > 0 {
+                    Style::default().fg(theme::ERROR)
+                } else {
+                    text
+                },
+            ),
+            Span::styled("   Warnings: ", dim),
+            Span::styled(summary.warnings.to_string(), text),
+            Span::styled("   Exit code: ", dim),
+            Span::styled(
+                summary
+                    .exit_code
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                text,
+            ),
+        ]),
+    ];
+    if let Some(failure) = &summary.failure {
         lines.push(Line::from(vec![
-            Span::raw("   "),
-            Span::styled(&engine.path, Style::default().fg(theme::TEXT_DIM)),
+            Span::styled("  Reason: ", dim),
+            Span::styled(failure.as_str(), Style::default().fg(theme::ERROR)),
         ]));
     }
 
+    if !summary.first_errors.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("  FIRST ERRORS", heading)));
+        for error in &summary.first_errors {
+            lines.push(Line::from(Span::styled(
+                format!("   {}", error),
+                Style::default().fg(theme::ERROR),
+            )));
+        }
+    }
+
+    if !summary.slowest_actions.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("  SLOWEST ACTIONS", heading)));
+        for (action, secs) in &summary.slowest_actions {
+            lines.push(Line::from(vec![
+                Span::styled(format!("   {:>7.2}s  ", secs), dim),
+                Span::styled(action.as_str(), text),
+            ]));
+        }
+    }
+
+    if !summary.binaries.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("  BINARIES", heading)));
+        for binary in summary.binaries.iter().take(6) {
+            lines.push(Line::from(Span::styled(format!("   {}", binary), text)));
+        }
+        if summary.binaries.len() > 6 {
+            lines.push(Line::from(Span::styled(
+                format!("   ... and {} more", summary.binaries.len() - 6),
+                dim,
+            )));
+        }
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
-        Span::styled("  [Enter]", theme::key_hint_style()),
-        Span::styled(" Select  ", theme::footer_style()),
-        Span::styled("[m]", theme::key_hint_style()),
-        Span::styled(" Manual  ", theme::footer_style()),
+        Span::styled("  [r]", theme::key_hint_style()),
+        Span::styled(" Rebuild  ", theme::footer_style()),
+        Span::styled("[c]", theme::key_hint_style()),
+        Span::styled(" Copy errors  ", theme::footer_style()),
+        Span::styled("[Enter/p]", theme::key_hint_style()),
+        Span::styled(" First problem  ", theme::footer_style()),
         Span::styled("[Esc]", theme::key_hint_style()),
-        Span::styled(" Cancel", theme::footer_style()),
+        Span::styled(" Close", theme::footer_style()),
     ]));
 
+    let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let popup = centered_rect(75, height, area);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Line::from(vec![Span::styled(
+            " Build Summary ",
+            theme::panel_title_style(),
+        )]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(true))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
     f.render_widget(Paragraph::new(lines), inner);
 }
 
-fn draw_editor_target_picker(
-    f: &mut Frame,
-    area: Rect,
-    app: &App,
-    project_index: usize,
-    candidates: &[String],
-    selected_index: usize,
-) {
-    let height = (candidates.len() as u16 + 8).min(area.height - 4).max(8);
-    let popup = centered_rect(60, height, area);
+fn draw_history(f: &mut Frame, area: Rect, app: &App, selected_index: usize) {
+    let height = (app.history.len() as u16 + 8).min(area.height - 4).max(9);
+    let popup = centered_rect(80, height, area);
     f.render_widget(Clear, popup);
 
-    let project_name = app
-        .config
-        .projects
-        .get(project_index)
-        .map(|p| p.name.as_str())
-        .unwrap_or("Project");
-
     let block = Block::default()
         .title(Line::from(vec![Span::styled(
-            format!(" Editor Target - {} ", project_name),
+            " Build History ",
             theme::panel_title_style(),
         )]))
         .borders(Borders::ALL)
@@ -187,13 +1216,19 @@ fn draw_editor_target_picker(
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
-    let mut lines = vec![Line::from(vec![Span::styled(
-        "  Pick the editor target to build.",
-        Style::default().fg(theme::TEXT_DIM),
-    )])];
-    lines.push(Line::from(""));
+    // Newest first; keep the selected row inside the visible window.
+    let visible = (inner.height as usize).saturating_sub(5).max(1);
+    let offset = (selected_index + 1).saturating_sub(visible);
+    let mut lines = vec![Line::from("")];
 
-    for (i, candidate) in candidates.iter().enumerate() {
+    for (i, record) in app
+        .history
+        .iter()
+        .rev()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+    {
         let selected = i == selected_index;
         let marker = if selected { " > " } else { "   " };
         let style = if selected {
@@ -201,20 +1236,57 @@ fn draw_editor_target_picker(
         } else {
             Style::default().fg(theme::TEXT)
         };
+        let result_style = match record.result {
+            BuildResult::Succeeded => Style::default().fg(theme::SUCCESS),
+            BuildResult::Failed => Style::default().fg(theme::ERROR),
+            BuildResult::Cancelled => Style::default().fg(theme::WARNING),
+        };
         lines.push(Line::from(vec![
             Span::styled(marker, style),
-            Span::styled(candidate, style),
+            Span::styled(
+                format!("{}  ", crate::app::format_timestamp(record.started_at)),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+            Span::styled(format!("{:<10}", record.result.to_string()), result_style),
+            Span::styled(
+                format!(
+                    "{} {} ({} {})",
+                    record.mode, record.project_name, record.configuration, record.platform
+                ),
+                style,
+            ),
+            Span::styled(
+                format!(
+                    "  {}  {}E {}W",
+                    crate::app::format_duration(record.duration_secs),
+                    record.errors,
+                    record.warnings
+                ),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
         ]));
     }
 
+    let detail = app
+        .history
+        .len()
+        .checked_sub(selected_index + 1)
+        .and_then(|i| app.history.get(i))
+        .map(|r| {
+            format!(
+                "  Target: {}   Engine: {}",
+                r.target.as_deref().unwrap_or("Auto (editor)"),
+                r.engine_version.as_deref().unwrap_or("unknown")
+            )
+        })
+        .unwrap_or_default();
     lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(detail, theme::subtitle_style())));
     lines.push(Line::from(vec![
         Span::styled("  [Enter]", theme::key_hint_style()),
-        Span::styled(" Select  ", theme::footer_style()),
-        Span::styled("[m]", theme::key_hint_style()),
-        Span::styled(" Manual  ", theme::footer_style()),
+        Span::styled(" Open log  ", theme::footer_style()),
         Span::styled("[Esc]", theme::key_hint_style()),
-        Span::styled(" Cancel", theme::footer_style()),
+        Span::styled(" Close", theme::footer_style()),
     ]));
 
     f.render_widget(Paragraph::new(lines), inner);
@@ -255,7 +1327,7 @@ fn draw_confirm(f: &mut Frame, area: Rect, message: &str) {
 }
 
 fn draw_help(f: &mut Frame, area: Rect) {
-    let popup = centered_rect(65, 22, area);
+    let popup = centered_rect(65, 32, area);
     f.render_widget(Clear, popup);
 
     let block = Block::default()
@@ -361,6 +1433,74 @@ fn draw_help(f: &mut Frame, area: Rect) {
             Span::styled("x", hl),
             Span::styled("  Clear logs", nl),
         ]),
+        Line::from(vec![
+            Span::styled("  k", hl),
+            Span::styled("  Package (BuildCookRun)  ", nl),
+            Span::styled("o", hl),
+            Span::styled("  Package options  ", nl),
+            Span::styled("u", hl),
+            Span::styled("  Build queue", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  F", hl),
+            Span::styled("  Generate project files (pick the IDE format)", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  p", hl),
+            Span::styled("  Cycle platform  ", nl),
+            Span::styled("g", hl),
+            Span::styled("  Cycle configuration  ", nl),
+            Span::styled("t", hl),
+            Span::styled("  Pick target", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  s", hl),
+            Span::styled("  Cycle preset  ", nl),
+            Span::styled("S", hl),
+            Span::styled("  Manage presets", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  a", hl),
+            Span::styled("  Extra UBT arguments  ", nl),
+            Span::styled("v", hl),
+            Span::styled("  Environment variables  ", nl),
+            Span::styled("h", hl),
+            Span::styled("  Hooks", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  e", hl),
+            Span::styled("  Launch editor  ", nl),
+            Span::styled("G", hl),
+            Span::styled("  Launch game  ", nl),
+            Span::styled("L", hl),
+            Span::styled("  Auto-launch after build", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  l", hl),
+            Span::styled("  Launch profiles  ", nl),
+            Span::styled("X", hl),
+            Span::styled("  Stop all  ", nl),
+            Span::styled("[ ]", hl),
+            Span::styled("  Switch log tab", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  H", hl),
+            Span::styled("  Build history  ", nl),
+            Span::styled("C", hl),
+            Span::styled("  Choose folders to clean (dry run, sizes)", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  F8", hl),
+            Span::styled("  Next error  ", nl),
+            Span::styled("Shift+F8", hl),
+            Span::styled("  Previous error (also n/N in Problems)", nl),
+        ]),
+        Line::from(vec![
+            Span::styled("  o", hl),
+            Span::styled("  Open location in editor (Problems/Logs)  ", nl),
+            Span::styled("O", hl),
+            Span::styled("  Editor command", nl),
+        ]),
         Line::from(vec![
             Span::styled("  Logs: ↑/↓", hl),
             Span::styled("      Up = older, Down = follow latest", nl),
@@ -1,4 +1,4 @@
-use super::{build_controls, engine_panel, header, log_panel, projects};
+use super::{build_controls, engine_panel, header, log_panel, problems, projects};
 use crate::app::App;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::Frame;
@@ -27,15 +27,24 @@ fn draw_body(f: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
         .split(area);
 
-    // Left column: projects panel
-    projects::draw_projects(f, cols[0], app);
+    // Left column: projects panel, with the problems panel below while there are diagnostics
+    if app.diagnostics.is_empty() {
+        projects::draw_projects(f, cols[0], app);
+    } else {
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(cols[0]);
+        projects::draw_projects(f, left[0], app);
+        problems::draw_problems(f, left[1], app);
+    }
 
     // Right column: split into engine / build controls / logs
     let right = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(4), // engine path
-            Constraint::Length(5), // build controls
+            Constraint::Length(7), // build controls
             Constraint::Min(5),    // logs
         ])
         .split(cols[1]);
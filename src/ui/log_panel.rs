@@ -10,13 +10,29 @@ pub fn draw_log_panel(f: &mut Frame, area: Rect, app: &App) {
     let focused = app.focused_panel() == FocusPanel::Logs;
 
     let dot_style = ratatui::style::Style::default().fg(theme::TEXT_DIM);
-    let title_spans = vec![
+    let mut title_spans = vec![
         Span::styled(" ", dot_style),
         Span::styled("● ", dot_style),
         Span::styled("● ", dot_style),
         Span::styled("● ", dot_style),
-        Span::styled("BUILD LOG ", theme::panel_title_style()),
     ];
+    let tabs = app.log_tabs();
+    if tabs.len() > 1 {
+        // One tab per launched process; the active one uses the title style.
+        for (i, tab) in tabs.iter().enumerate() {
+            let style = if i == app.log_tab {
+                theme::panel_title_style()
+            } else {
+                dot_style
+            };
+            title_spans.push(Span::styled(format!("{} ", tab.to_uppercase()), style));
+        }
+        if focused {
+            title_spans.push(Span::styled("[ ] ", theme::key_hint_style()));
+        }
+    } else {
+        title_spans.push(Span::styled("BUILD LOG ", theme::panel_title_style()));
+    }
 
     let block = Block::default()
         .title(Line::from(title_spans))
@@ -27,7 +43,8 @@ pub fn draw_log_panel(f: &mut Frame, area: Rect, app: &App) {
     let inner = block.inner(area);
     f.render_widget(block, area);
 
-    if app.logs.is_empty() {
+    let logs = app.visible_logs();
+    if logs.is_empty() {
         let empty = Paragraph::new(vec![Line::from(Span::styled(
             "  > No logs yet.",
             ratatui::style::Style::default()
@@ -39,7 +56,7 @@ pub fn draw_log_panel(f: &mut Frame, area: Rect, app: &App) {
     }
 
     let visible_height = inner.height as usize;
-    let total = app.logs.len();
+    let total = logs.len();
 
     let max_top = total.saturating_sub(visible_height);
 
@@ -55,14 +72,25 @@ pub fn draw_log_panel(f: &mut Frame, area: Rect, app: &App) {
     };
 
     let end = (scroll + visible_height).min(total);
-    let visible_logs = &app.logs[scroll..end];
+    let visible_logs = &logs[scroll..end];
 
+    // Highlight the build log line of the diagnostic selected in the problems panel.
+    let problem_line = app
+        .selected_problem()
+        .filter(|_| app.log_tab == 0)
+        .map(|d| d.log_index);
     let lines: Vec<Line> = visible_logs
         .iter()
-        .map(|log| {
+        .enumerate()
+        .map(|(i, log)| {
+            let style = if problem_line == Some(scroll + i) {
+                theme::log_style(&log.level).add_modifier(ratatui::style::Modifier::REVERSED)
+            } else {
+                theme::log_style(&log.level)
+            };
             Line::from(vec![
                 Span::styled(" > ", ratatui::style::Style::default().fg(theme::TEXT_DIM)),
-                Span::styled(&log.text, theme::log_style(&log.level)),
+                Span::styled(&log.text, style),
             ])
         })
         .collect();
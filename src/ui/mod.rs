@@ -4,6 +4,7 @@ pub mod engine_panel;
 pub mod header;
 pub mod layout;
 pub mod log_panel;
+pub mod problems;
 pub mod projects;
 pub mod starfield;
 pub mod theme;
@@ -0,0 +1,107 @@
+use super::theme;
+use crate::app::App;
+use crate::types::{FocusPanel, Severity};
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::Frame;
+
+pub fn draw_problems(f: &mut Frame, area: Rect, app: &App) {
+    let focused = app.focused_panel() == FocusPanel::Problems;
+
+    let errors = app
+        .diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    let warnings = app.diagnostics.len() - errors;
+
+    let block = Block::default()
+        .title(Line::from(vec![
+            Span::styled(" PROBLEMS ", theme::panel_title_style()),
+            Span::styled(
+                format!("{} errors, {} warnings ", errors, warnings),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(theme::border_style(focused))
+        .style(Style::default().bg(theme::SURFACE));
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // One header line per file, then one line per diagnostic.
+    let mut lines = Vec::new();
+    let mut selected_line = 0;
+    let mut row = 0;
+    for (file, indices) in app.problem_groups() {
+        let header = match file {
+            Some(path) => short_path(path),
+            None => "UnrealBuildTool".to_string(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {}", header),
+                Style::default()
+                    .fg(theme::ACCENT)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" ({})", indices.len()),
+                Style::default().fg(theme::TEXT_DIM),
+            ),
+        ]));
+
+        for index in indices {
+            let diagnostic = &app.diagnostics[index];
+            let selected = app.problem_selected == Some(row);
+            if selected {
+                selected_line = lines.len();
+            }
+            let marker = if selected { " > " } else { "   " };
+            let (label, level_style) = match diagnostic.severity {
+                Severity::Error => ("error", Style::default().fg(theme::ERROR)),
+                Severity::Warning => ("warning", Style::default().fg(theme::WARNING)),
+            };
+            let location = match (diagnostic.line, diagnostic.column) {
+                (Some(line), Some(column)) => format!("{}:{} ", line, column),
+                (Some(line), None) => format!("{} ", line),
+                _ => String::new(),
+            };
+            let code = diagnostic
+                .code
+                .as_ref()
+                .map(|c| format!(" {}", c))
+                .unwrap_or_default();
+            let text_style = if selected {
+                theme::selected_style().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme::TEXT)
+            };
+            lines.push(Line::from(vec![
+                Span::styled(marker, text_style),
+                Span::styled(location, Style::default().fg(theme::TEXT_DIM)),
+                Span::styled(format!("{}{}: ", label, code), level_style),
+                Span::styled(diagnostic.message.as_str(), text_style),
+            ]));
+            row += 1;
+        }
+    }
+
+    // Keep the selected diagnostic in view.
+    let height = inner.height as usize;
+    let scroll = (selected_line + 1).saturating_sub(height);
+    f.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), inner);
+}
+
+/// The last two components of a path, e.g. "Private/MyActor.cpp".
+fn short_path(path: &str) -> String {
+    let parts: Vec<&str> = path.split(['/', '\\']).filter(|p| !p.is_empty()).collect();
+    if parts.len() <= 2 {
+        path.to_string()
+    } else {
+        parts[parts.len() - 2..].join("/")
+    }
+}
@@ -23,7 +23,7 @@ impl Widget for StarfieldWidget {
 
         let mut rng = StdRng::seed_from_u64(42_424_242);
         let cell_count = (area.width as usize) * (area.height as usize);
-        let count = (cell_count / 8).max(40).min(500);
+        let count = (cell_count / 8).clamp(40, 500);
         let t = self.tick as f64;
 
         for _ in 0..count {